@@ -0,0 +1,49 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use mem_rs::prelude::*;
+
+//Compares refresh and refresh_fast while attached, run with cargo bench on windows.
+//While attached, refresh borrows the process data 5 times (from_buffer, attached and the handle for GetExitCodeProcess, attached, redump_on_refresh),
+//refresh_fast borrows it once. Both make a single GetExitCodeProcess call.
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn time(iterations: u32, mut f: impl FnMut() -> Result<RefreshStatus, String>) -> Duration
+{
+    let start = Instant::now();
+    for _ in 0..iterations
+    {
+        assert_eq!(black_box(f()), Ok(RefreshStatus::StillAttached));
+    }
+    return start.elapsed() / iterations;
+}
+
+fn main()
+{
+    //Attach to the benchmark itself, so that there is always a running process
+    let mut process = ProcessBuilder::new().dump_main_module(false).build();
+    process.attach_pid(std::process::id()).expect("Failed to attach to the current process");
+
+    let refresh = time(ITERATIONS, || process.refresh());
+    let refresh_fast = time(ITERATIONS, || process.refresh_fast());
+
+    println!("{} iterations while attached", ITERATIONS);
+    println!("refresh:      {:?}", refresh);
+    println!("refresh_fast: {:?}", refresh_fast);
+}
@@ -0,0 +1,75 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use mem_rs::helpers::{pattern_byte_matches, scan, to_pattern, PatternByte};
+
+//Compares the Boyer-Moore-Horspool scan against a naive scan on a module sized haystack, run with cargo bench
+
+const HAYSTACK_SIZE: usize = 64 * 1024 * 1024;
+const ITERATIONS: u32 = 5;
+
+fn naive_scan(haystack: &[u8], needle: &[PatternByte]) -> Option<usize>
+{
+    for i in 0..haystack.len() - needle.len() + 1
+    {
+        if needle.iter().enumerate().all(|(j, pattern_byte)| pattern_byte_matches(pattern_byte, haystack[i + j]))
+        {
+            return Some(i);
+        }
+    }
+    return None;
+}
+
+fn time(iterations: u32, mut f: impl FnMut() -> Option<usize>) -> Duration
+{
+    let start = Instant::now();
+    for _ in 0..iterations
+    {
+        black_box(f());
+    }
+    return start.elapsed() / iterations;
+}
+
+fn main()
+{
+    //xorshift filled haystack, with the pattern at the very end so that both scans search all of it
+    let mut state = 0x2545F4914F6CDD1Du64;
+    let mut haystack: Vec<u8> = (0..HAYSTACK_SIZE).map(|_|
+    {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as u8
+    }).collect();
+
+    let pattern = "48 8B 05 ? ? ? ? 48 8B 88 ? ? ? ? 48 85 C9 74 4? E8";
+    let needle = to_pattern(pattern);
+    let bytes = [0x48, 0x8B, 0x05, 0x11, 0x22, 0x33, 0x44, 0x48, 0x8B, 0x88, 0x55, 0x66, 0x77, 0x88, 0x48, 0x85, 0xC9, 0x74, 0x42, 0xE8];
+    let position = haystack.len() - bytes.len();
+    haystack[position..].copy_from_slice(&bytes);
+
+    assert_eq!(scan(&haystack, &needle), Some(position));
+    assert_eq!(naive_scan(&haystack, &needle), Some(position));
+
+    let horspool = time(ITERATIONS, || scan(black_box(&haystack), black_box(&needle)));
+    let naive = time(ITERATIONS, || naive_scan(black_box(&haystack), black_box(&needle)));
+
+    println!("{} MiB, pattern \"{}\"", HAYSTACK_SIZE / (1024 * 1024), pattern);
+    println!("scan:  {:?}", horspool);
+    println!("naive: {:?}", naive);
+}
@@ -50,16 +50,11 @@ impl Ds1
 
     pub fn refresh(&mut self) -> Result<(), String>
     {
-        if !self.process.is_attached()
+        if self.process.refresh()? != RefreshStatus::StillAttached
         {
-            self.process.refresh()?;
             self.game_data_man = self.process.scan_rel("GameDataMan", "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0])?;
             self.ai_timer = self.process.scan_rel("AI Timer", "48 8b 0d ? ? ? ? 48 85 c9 74 0e 48 83 c1 28", 3, 7, vec![0])?;
         }
-        else
-        {
-            self.process.refresh()?;
-        }
         Ok(())
     }
 
@@ -17,62 +17,337 @@
 use std::path::Path;
 use windows::core::{PCSTR, PCWSTR};
 
-/// Naive linear search for a needle in a haystack with wildcards
-pub fn scan(haystack: &[u8], needle: &[Option<u8>]) -> Option<usize>
+/// A single byte of a pattern as (value, mask). A haystack byte matches when (byte & mask) == (value & mask).
+/// A mask of 0xFF matches the exact value, 0x00 is a full wildcard and 0xF0 or 0x0F only match one nibble.
+pub type PatternByte = (u8, u8);
+
+/// Returns if a byte matches a pattern byte
+pub fn pattern_byte_matches(pattern_byte: &PatternByte, byte: u8) -> bool
+{
+    let (value, mask) = *pattern_byte;
+    return byte & mask == value & mask;
+}
+
+/// Boyer-Moore-Horspool search for a needle in a haystack with wildcards.
+/// A (partial) wildcard matches several bytes, so the skip for every byte it matches is limited by its position in the needle.
+pub fn scan(haystack: &[u8], needle: &[PatternByte]) -> Option<usize>
 {
-    if haystack.len() == 0
+    if haystack.len() == 0 || needle.len() > haystack.len()
     {
         return None;
     }
+    if needle.len() == 0
+    {
+        return Some(0);
+    }
+
+    //Bad character table, built from every position but the last
+    let last = needle.len() - 1;
+    let mut skip = [needle.len(); 256];
+    for i in 0..last
+    {
+        for byte in 0..=255u8
+        {
+            if pattern_byte_matches(&needle[i], byte)
+            {
+                skip[byte as usize] = last - i;
+            }
+        }
+    }
 
-    for i in 0..haystack.len() - needle.len()
+    let mut i = 0;
+    while i + last < haystack.len()
     {
+        let mut found = true;
+        for j in (0..needle.len()).rev()
+        {
+            if !pattern_byte_matches(&needle[j], haystack[i + j])
+            {
+                found = false;
+                break;
+            }
+        }
+        if found
+        {
+            return Some(i);
+        }
+        i += skip[haystack[i + last] as usize];
+    }
+    return None;
+}
+
+/// Naive linear search for all occurrences of a needle in a haystack with wildcards.
+/// Stops after limit matches when a limit is given.
+pub fn scan_all(haystack: &[u8], needle: &[PatternByte], limit: Option<usize>) -> Vec<usize>
+{
+    let mut result = Vec::new();
+    if needle.len() > haystack.len()
+    {
+        return result;
+    }
+
+    for i in 0..haystack.len() - needle.len() + 1
+    {
+        if limit.is_some() && result.len() >= limit.unwrap()
+        {
+            break;
+        }
+
         let mut found = true;
         for j in 0..needle.len()
         {
-            if let Some(byte) = needle[j]
+            if !pattern_byte_matches(&needle[j], haystack[i + j])
             {
-                if byte != haystack[i + j]
+                found = false;
+                break;
+            }
+        }
+        if found
+        {
+            result.push(i);
+        }
+    }
+    return result;
+}
+
+/// Naive linear search for multiple needles in a haystack with wildcards, in a single pass over the haystack.
+/// Returns the first match for each needle, in the same order as the needles.
+pub fn scan_many(haystack: &[u8], needles: &[Vec<PatternByte>]) -> Vec<Option<usize>>
+{
+    let mut result: Vec<Option<usize>> = vec![None; needles.len()];
+    let mut remaining = needles.len();
+
+    for i in 0..haystack.len()
+    {
+        if remaining == 0
+        {
+            break;
+        }
+
+        for n in 0..needles.len()
+        {
+            let needle = &needles[n];
+            if result[n].is_some() || i + needle.len() > haystack.len()
+            {
+                continue;
+            }
+
+            let mut found = true;
+            for j in 0..needle.len()
+            {
+                if !pattern_byte_matches(&needle[j], haystack[i + j])
                 {
                     found = false;
                     break;
                 }
             }
+            if found
+            {
+                result[n] = Some(i);
+                remaining -= 1;
+            }
         }
-        if found
+    }
+    return result;
+}
+
+/// Compares two captures of (address, value) pairs and returns the addresses whose value changed.
+/// Addresses that are missing from either capture are ignored.
+pub fn diff_addresses(before: &[(usize, Vec<u8>)], after: &[(usize, Vec<u8>)]) -> Vec<usize>
+{
+    let mut result = Vec::new();
+    for (address, value) in before
+    {
+        if let Some((_, after_value)) = after.iter().find(|(after_address, _)| after_address == address)
         {
-            return Some(i);
+            if value != after_value
+            {
+                result.push(*address);
+            }
         }
     }
-    return None;
+    return result;
+}
+
+/// Reads a sign-extended little endian displacement of 1, 2, 4 or 8 bytes from the start of a buffer.
+/// Returns None when the buffer is too short or the size is not supported.
+pub fn read_displacement(buffer: &[u8], size: usize) -> Option<isize>
+{
+    let bytes = buffer.get(..size)?;
+    return match size
+    {
+        1 => Some(bytes[0] as i8 as isize),
+        2 => Some(i16::from_le_bytes(bytes.try_into().unwrap()) as isize),
+        4 => Some(i32::from_le_bytes(bytes.try_into().unwrap()) as isize),
+        8 => Some(i64::from_le_bytes(bytes.try_into().unwrap()) as isize),
+        _ => None,
+    };
 }
 
 /// Converts a string of hex characters into a byte pattern with wildcards.
-/// ? is the character used for wildcards.
+/// ? or ?? is the wildcard for a whole byte, a single nibble can be a wildcard as well, like 4? or ?8.
 /// Hex characters don't have to be prefixed with 0x
-pub fn to_pattern(str: &str) -> Vec<Option<u8>>
+pub fn to_pattern(str: &str) -> Vec<PatternByte>
 {
     let mut vec = Vec::new();
     for substr in str.split(" ")
     {
-        if substr == "?"
+        if substr == "?" || substr == "??"
         {
-            vec.push(None);
+            vec.push((0, 0));
+        }
+        else if substr.len() == 2 && substr.ends_with('?')
+        {
+            vec.push((u8::from_str_radix(&substr[..1], 16).expect("invalid hex string in pattern string") << 4, 0xF0));
+        }
+        else if substr.len() == 2 && substr.starts_with('?')
+        {
+            vec.push((u8::from_str_radix(&substr[1..], 16).expect("invalid hex string in pattern string"), 0x0F));
         }
         else
         {
-            vec.push(Some(u8::from_str_radix(substr, 16).expect("invalid hex string in pattern string")));
+            vec.push((u8::from_str_radix(substr, 16).expect("invalid hex string in pattern string"), 0xFF));
         }
     }
     return vec;
 }
 
+/// Converts a byte array and an IDA/x64dbg style mask into a byte pattern with wildcards.
+/// x or X in the mask keeps the byte at that position, . or ? turns it into a wildcard.
+/// The byte array and the mask must have the same length.
+pub fn to_pattern_masked(bytes: &[u8], mask: &str) -> Result<Vec<PatternByte>, String>
+{
+    if bytes.len() != mask.len()
+    {
+        return Err(format!("Pattern length {} does not match mask length {}", bytes.len(), mask.len()));
+    }
+
+    let mut vec = Vec::new();
+    for (byte, c) in bytes.iter().zip(mask.chars())
+    {
+        match c
+        {
+            'x' | 'X' => vec.push((*byte, 0xFF)),
+            '.' | '?' => vec.push((0, 0)),
+            _ => return Err(format!("Invalid character '{}' in mask", c)),
+        }
+    }
+    return Ok(vec);
+}
+
 /// Retrieve only the filename portion from a filepath.
 pub fn get_file_name_from_string(str: &String) -> String
 {
     return String::from(Path::new(&str).file_name().unwrap().to_str().unwrap());
 }
 
+/// Normalize a process name: strips directory components and appends .exe when there is no extension.
+pub fn normalize_process_name(name: &str) -> String
+{
+    let file_name = Path::new(name).file_name().and_then(|f| f.to_str()).unwrap_or(name);
+    if Path::new(file_name).extension().is_none()
+    {
+        return format!("{}.exe", file_name);
+    }
+    return String::from(file_name);
+}
+
+/// Splits a command line into arguments with the same rules as CommandLineToArgvW.
+/// The first argument is the program, which ends at the next quote when quoted and at whitespace otherwise.
+/// In the other arguments, 2n backslashes followed by a quote produce n backslashes and toggle quoting,
+/// 2n + 1 backslashes followed by a quote produce n backslashes and a literal quote, and two quotes inside a quoted argument produce a literal quote.
+pub fn split_command_line(command_line: &str) -> Vec<String>
+{
+    let chars: Vec<char> = command_line.chars().collect();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    //The program name
+    let mut program = String::new();
+    if chars.first() == Some(&'"')
+    {
+        i = 1;
+        while i < chars.len() && chars[i] != '"'
+        {
+            program.push(chars[i]);
+            i += 1;
+        }
+        i += 1;
+    }
+    else
+    {
+        while i < chars.len() && chars[i] != ' ' && chars[i] != '\t'
+        {
+            program.push(chars[i]);
+            i += 1;
+        }
+    }
+    result.push(program);
+
+    loop
+    {
+        while i < chars.len() && (chars[i] == ' ' || chars[i] == '\t')
+        {
+            i += 1;
+        }
+        if i >= chars.len()
+        {
+            return result;
+        }
+
+        let mut arg = String::new();
+        let mut quoted = false;
+        while i < chars.len() && (quoted || (chars[i] != ' ' && chars[i] != '\t'))
+        {
+            match chars[i]
+            {
+                '\\' =>
+                {
+                    let mut backslashes = 0;
+                    while i < chars.len() && chars[i] == '\\'
+                    {
+                        backslashes += 1;
+                        i += 1;
+                    }
+
+                    if i < chars.len() && chars[i] == '"'
+                    {
+                        arg.extend(std::iter::repeat_n('\\', backslashes / 2));
+                        if backslashes % 2 == 1
+                        {
+                            arg.push('"');
+                            i += 1;
+                        }
+                    }
+                    else
+                    {
+                        arg.extend(std::iter::repeat_n('\\', backslashes));
+                    }
+                }
+                '"' =>
+                {
+                    if quoted && i + 1 < chars.len() && chars[i + 1] == '"'
+                    {
+                        arg.push('"');
+                        i += 2;
+                    }
+                    else
+                    {
+                        quoted = !quoted;
+                        i += 1;
+                    }
+                }
+                c =>
+                {
+                    arg.push(c);
+                    i += 1;
+                }
+            }
+        }
+        result.push(arg);
+    }
+}
+
 /// Win32 memes. Use with caution.
 pub fn vec_u16_to_u8(vec_u16: &Vec<u16>) -> Vec<u8>
 {
@@ -82,7 +357,8 @@ pub fn vec_u16_to_u8(vec_u16: &Vec<u16>) -> Vec<u8>
 /// Win32 memes. Use with caution.
 pub fn w32str_to_string(w32str: &Vec<u16>) -> String
 {
-    return w32str.iter().map(|&v| (v & 0xFF) as u8).take_while(|&c| c != 0).map(|c| c as char).collect();
+    let length = w32str.iter().position(|&c| c == 0).unwrap_or(w32str.len());
+    return String::from_utf16_lossy(&w32str[..length]);
 }
 
 /// Win32 memes. Use with caution.
@@ -102,4 +378,148 @@ pub fn get_pcwstr_from_str(str: &str) -> PCWSTR
 pub fn get_pcstr_from_str(str: &str) -> PCSTR
 {
     return PCSTR(str.as_ptr());
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    //Reference implementation to compare the Boyer-Moore-Horspool scan against
+    fn naive_scan(haystack: &[u8], needle: &[PatternByte]) -> Option<usize>
+    {
+        if needle.len() > haystack.len()
+        {
+            return None;
+        }
+        return (0..=haystack.len() - needle.len()).find(|&i| needle.iter().enumerate().all(|(j, pattern_byte)| pattern_byte_matches(pattern_byte, haystack[i + j])));
+    }
+
+    //xorshift, good enough to generate test data without a dependency
+    fn next_random(state: &mut u64) -> u64
+    {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        return *state;
+    }
+
+    #[test]
+    fn scan_matches_a_naive_scan()
+    {
+        let mut state = 0x2545F4914F6CDD1D;
+        for _ in 0..5000
+        {
+            //A small alphabet produces many partial matches
+            let alphabet = 1 + (next_random(&mut state) % 8) as u8;
+            let haystack: Vec<u8> = (0..next_random(&mut state) % 64).map(|_| (next_random(&mut state) % alphabet as u64) as u8 * 0x11).collect();
+
+            let needle_len = 1 + (next_random(&mut state) % 8) as usize;
+            let mut needle: Vec<PatternByte> = (0..needle_len).map(|_| ((next_random(&mut state) % alphabet as u64) as u8 * 0x11, 0xFF)).collect();
+
+            //Usually embed the needle in the haystack, so that there is something to find
+            if haystack.len() >= needle_len && next_random(&mut state) % 4 != 0
+            {
+                let position = (next_random(&mut state) % (haystack.len() - needle_len + 1) as u64) as usize;
+                for j in 0..needle_len
+                {
+                    needle[j].0 = haystack[position + j];
+                }
+            }
+
+            //Add whole and nibble wildcards
+            for pattern_byte in needle.iter_mut()
+            {
+                pattern_byte.1 = match next_random(&mut state) % 6
+                {
+                    0 => 0x00,
+                    1 => 0xF0,
+                    2 => 0x0F,
+                    _ => 0xFF,
+                };
+            }
+
+            assert_eq!(scan(&haystack, &needle), naive_scan(&haystack, &needle), "haystack {:02x?} needle {:02x?}", haystack, needle);
+            assert_eq!(scan_all(&haystack, &needle, Some(1)).first().copied(), naive_scan(&haystack, &needle));
+        }
+    }
+
+    #[test]
+    fn scan_finds_a_match_at_the_end_of_the_haystack()
+    {
+        let haystack = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        assert_eq!(scan(&haystack, &to_pattern("44 55")), Some(4));
+        assert_eq!(scan(&haystack, &to_pattern("00 11 22 33 44 55")), Some(0));
+        assert_eq!(scan(&haystack, &to_pattern("55")), Some(5));
+        assert_eq!(scan_all(&haystack, &to_pattern("? 55"), None), vec![4]);
+    }
+
+    #[test]
+    fn scan_returns_none_when_the_needle_is_longer_than_the_haystack()
+    {
+        let haystack = [0x00, 0x11, 0x22, 0x33];
+        let needle = vec![(0, 0); 16];
+        assert_eq!(scan(&haystack, &needle), None);
+        assert!(scan_all(&haystack, &needle, None).is_empty());
+        assert_eq!(scan_many(&haystack, &[needle]), vec![None]);
+    }
+
+    #[test]
+    fn to_pattern_parses_nibble_wildcards()
+    {
+        assert_eq!(to_pattern("48 ? ?? 4? ?8"), vec![(0x48, 0xFF), (0, 0), (0, 0), (0x40, 0xF0), (0x08, 0x0F)]);
+    }
+
+    #[test]
+    fn nibble_wildcards_match_one_nibble()
+    {
+        assert!(pattern_byte_matches(&(0x40, 0xF0), 0x4A));
+        assert!(!pattern_byte_matches(&(0x40, 0xF0), 0x5A));
+        assert!(pattern_byte_matches(&(0x08, 0x0F), 0xE8));
+        assert!(!pattern_byte_matches(&(0x08, 0x0F), 0xE9));
+        assert!(pattern_byte_matches(&(0, 0), 0xFF));
+
+        let haystack = [0x41, 0x58, 0x4C, 0xE8, 0x90];
+        assert_eq!(scan(&haystack, &to_pattern("4? ?8")), Some(0));
+        assert_eq!(scan(&haystack, &to_pattern("4? ?8 90")), Some(2));
+        assert_eq!(scan(&haystack, &to_pattern("?? ?8 90")), Some(2));
+        assert_eq!(scan(&haystack, &to_pattern("5? ?9")), None);
+        assert_eq!(scan_all(&haystack, &to_pattern("4?"), None), vec![0, 2]);
+    }
+
+    #[test]
+    fn partial_masks_limit_the_skip()
+    {
+        //The byte under the last position of the first window only matches the 4? at the start of the needle,
+        //skipping the full needle length past it would miss the match at 3
+        let haystack = [0x00, 0x00, 0x00, 0x4F, 0x20, 0x30, 0x40];
+        assert_eq!(scan(&haystack, &to_pattern("4? 20 30 40")), Some(3));
+        //The same for a low nibble wildcard
+        let haystack = [0x00, 0x00, 0x00, 0xA7, 0x20, 0x30, 0x47];
+        assert_eq!(scan(&haystack, &to_pattern("?7 20 30 47")), Some(3));
+    }
+
+    #[test]
+    fn command_line_splits_on_whitespace_and_quotes()
+    {
+        assert_eq!(split_command_line("game.exe -a  \t-b"), vec!["game.exe", "-a", "-b"]);
+        assert_eq!(split_command_line("\"C:\\Program Files\\game.exe\" \"a b\" c"), vec!["C:\\Program Files\\game.exe", "a b", "c"]);
+        assert_eq!(split_command_line("game.exe a\"b c\"d"), vec!["game.exe", "ab cd"]);
+        assert_eq!(split_command_line("game.exe \"a\"\"b\""), vec!["game.exe", "a\"b"]);
+        assert_eq!(split_command_line("game.exe \"\""), vec!["game.exe", ""]);
+    }
+
+    #[test]
+    fn command_line_backslashes()
+    {
+        //Backslashes are literal unless they precede a quote
+        assert_eq!(split_command_line("game.exe a\\\\b c\\"), vec!["game.exe", "a\\\\b", "c\\"]);
+        //2n backslashes and a quote: n backslashes and the quote toggles quoting
+        assert_eq!(split_command_line("game.exe \"a\\\\\" b"), vec!["game.exe", "a\\", "b"]);
+        //2n + 1 backslashes and a quote: n backslashes and a literal quote
+        assert_eq!(split_command_line("game.exe a\\\\\\\"b"), vec!["game.exe", "a\\\"b"]);
+        assert_eq!(split_command_line("game.exe \\\"a"), vec!["game.exe", "\"a"]);
+        //The program name doesn't follow the backslash rules
+        assert_eq!(split_command_line("C:\\game\\ a"), vec!["C:\\game\\", "a"]);
+    }
+}
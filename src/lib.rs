@@ -15,15 +15,35 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 mod process_data;
-mod process_module;
+pub mod process_module;
+pub mod process_handle;
 pub mod helpers;
 pub mod read_write;
 pub mod process;
 pub mod pointer;
+pub mod minidump_process;
+pub mod memory_basic_information;
+pub mod process_memory_info;
+pub mod resolved_pointer;
+pub mod scan_pointer;
+pub mod signature_set;
+pub mod offset_table;
+pub mod tracked_value;
+pub mod local_process;
 
 pub mod prelude
 {
 	pub use crate::read_write::*;
 	pub use crate::process::*;
 	pub use crate::pointer::*;
+	pub use crate::resolved_pointer::*;
+	pub use crate::scan_pointer::*;
+	pub use crate::minidump_process::*;
+	pub use crate::memory_basic_information::*;
+	pub use crate::process_memory_info::*;
+	pub use crate::process_handle::*;
+	pub use crate::signature_set::*;
+	pub use crate::offset_table::*;
+	pub use crate::tracked_value::*;
+	pub use crate::local_process::*;
 }
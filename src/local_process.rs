@@ -0,0 +1,157 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use windows::core::PCWSTR;
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows::Win32::System::Memory::{MEM_COMMIT, MEMORY_BASIC_INFORMATION, PAGE_EXECUTE_READWRITE, PAGE_EXECUTE_WRITECOPY, PAGE_GUARD, PAGE_NOACCESS, PAGE_READWRITE, PAGE_WRITECOPY, VirtualQuery};
+use crate::read_write::{BaseReadWrite, ReadWrite};
+
+/// Reads and writes the memory of the current process directly, for use from a dll that was injected into the target.
+/// Relative reads and writes are relative to the main module of the current process.
+/// When validation is enabled, every access is first checked with VirtualQuery, and fails instead of crashing when the memory is not accessible.
+///
+/// # Example
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let process = LocalProcess::new(true);
+/// let health = process.read_u32_rel(Some(0x1234));
+/// ```
+pub struct LocalProcess
+{
+    base_address: usize,
+    validate: bool,
+}
+
+impl LocalProcess
+{
+    pub fn new(validate: bool) -> Self
+    {
+        let base_address = unsafe { GetModuleHandleW(PCWSTR::null()).map(|module| module.0 as usize).unwrap_or(0) };
+        LocalProcess { base_address, validate }
+    }
+
+    /// Get the base address of the main module of the current process
+    pub fn get_base_address(&self) -> usize
+    {
+        return self.base_address;
+    }
+
+    //Checks that every page in the range is committed and readable, or writable when write is true
+    fn is_accessible(&self, address: usize, len: usize, write: bool) -> bool
+    {
+        if address == 0
+        {
+            return false;
+        }
+        if !self.validate
+        {
+            return true;
+        }
+
+        let end = match address.checked_add(len)
+        {
+            Some(end) => end,
+            None => return false,
+        };
+
+        let mut current = address;
+        while current < end
+        {
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+            if unsafe { VirtualQuery(Some(current as *const c_void), &mut info, size_of::<MEMORY_BASIC_INFORMATION>()) } == 0
+            {
+                return false;
+            }
+
+            if info.State != MEM_COMMIT || info.Protect == PAGE_NOACCESS || (info.Protect & PAGE_GUARD).0 != 0
+            {
+                return false;
+            }
+            if write && (info.Protect & (PAGE_READWRITE | PAGE_WRITECOPY | PAGE_EXECUTE_READWRITE | PAGE_EXECUTE_WRITECOPY)).0 == 0
+            {
+                return false;
+            }
+
+            current = info.BaseAddress as usize + info.RegionSize;
+        }
+        return true;
+    }
+}
+
+impl BaseReadWrite for LocalProcess
+{
+    fn read_memory_rel(&self, offset: Option<usize>, buffer: &mut [u8]) -> bool
+    {
+        return self.read_memory_abs(self.base_address + offset.unwrap_or(0), buffer);
+    }
+
+    fn write_memory_rel(&self, offset: Option<usize>, buffer: &[u8]) -> bool
+    {
+        return self.write_memory_abs(self.base_address + offset.unwrap_or(0), buffer);
+    }
+
+    fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        if buffer.is_empty()
+        {
+            return true;
+        }
+        if !self.is_accessible(address, buffer.len(), false)
+        {
+            return false;
+        }
+        unsafe { std::ptr::copy_nonoverlapping(address as *const u8, buffer.as_mut_ptr(), buffer.len()); }
+        return true;
+    }
+
+    fn write_memory_abs(&self, address: usize, buffer: &[u8]) -> bool
+    {
+        if buffer.is_empty()
+        {
+            return true;
+        }
+        if !self.is_accessible(address, buffer.len(), true)
+        {
+            return false;
+        }
+        unsafe { std::ptr::copy_nonoverlapping(buffer.as_ptr(), address as *mut u8, buffer.len()); }
+        return true;
+    }
+}
+
+impl ReadWrite for LocalProcess{}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::prelude::*;
+
+    #[test]
+    fn absolute_reads_and_writes_use_the_heap_address()
+    {
+        let process = LocalProcess::new(false);
+        let mut value = Box::new(0u32);
+        let address = &mut *value as *mut u32 as usize;
+
+        process.write_u32_abs(address, 0xDEADBEEF);
+        assert_eq!(process.read_u32_abs(address), 0xDEADBEEF);
+        assert_eq!(*value, 0xDEADBEEF);
+    }
+}
@@ -0,0 +1,45 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::System::Memory::{MEMORY_BASIC_INFORMATION, PAGE_PROTECTION_FLAGS, PAGE_TYPE, VIRTUAL_ALLOCATION_TYPE};
+
+/// Information about a region of pages in the memory of a process, as returned by VirtualQueryEx
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryBasicInformation
+{
+    pub base_address: usize,
+    pub allocation_base: usize,
+    pub region_size: usize,
+    pub state: VIRTUAL_ALLOCATION_TYPE,
+    pub protect: PAGE_PROTECTION_FLAGS,
+    pub memory_type: PAGE_TYPE,
+}
+
+impl From<MEMORY_BASIC_INFORMATION> for MemoryBasicInformation
+{
+    fn from(info: MEMORY_BASIC_INFORMATION) -> Self
+    {
+        MemoryBasicInformation
+        {
+            base_address: info.BaseAddress as usize,
+            allocation_base: info.AllocationBase as usize,
+            region_size: info.RegionSize,
+            state: info.State,
+            protect: info.Protect,
+            memory_type: info.Type,
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::helpers::{scan, to_pattern};
+use crate::process::Process;
+use crate::read_write::{BaseReadWrite, ReadWrite};
+
+const MINIDUMP_SIGNATURE: u32 = 0x504d444d; //MDMP
+const MODULE_LIST_STREAM: u32 = 4;
+const MEMORY_LIST_STREAM: u32 = 5;
+const MEMORY64_LIST_STREAM: u32 = 9;
+
+struct MemoryRange
+{
+    start: usize,
+    size: usize,
+    file_offset: usize,
+}
+
+/// Reads memory from a minidump (.dmp) file instead of a live process, for post-mortem analysis.
+/// Implements the same read functions as a live process. Relative reads are relative to the main module
+/// (the first module in the dump). Writes always fail. Use to_process to scan and resolve pointers with the regular Process api.
+///
+/// # Example
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let dump = MinidumpProcess::from_file(r#"C:\dumps\crash.dmp"#)?;
+/// let process = dump.to_process();
+/// let game_data_man = process.scan_rel("GameDataMan", "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0])?;
+/// let igt = game_data_man.read_u32_rel(Some(0xa4));
+/// ```
+pub struct MinidumpProcess
+{
+    data: Vec<u8>,
+    ranges: Vec<MemoryRange>,
+    main_module_base: usize,
+    main_module_size: usize,
+}
+
+impl MinidumpProcess
+{
+    /// Load and parse a minidump file
+    pub fn from_file(path: &str) -> Result<Self, String>
+    {
+        match std::fs::read(path)
+        {
+            Ok(data) => return MinidumpProcess::from_bytes(data),
+            Err(e) => return Err(format!("Failed to read minidump: {}", e)),
+        }
+    }
+
+    /// Parse a minidump from memory
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, String>
+    {
+        if read_u32(&data, 0) != Some(MINIDUMP_SIGNATURE)
+        {
+            return Err(String::from("Not a minidump"));
+        }
+
+        let stream_count = read_u32(&data, 8).ok_or("Invalid minidump header")? as usize;
+        let directory_rva = read_u32(&data, 12).ok_or("Invalid minidump header")? as usize;
+
+        let mut minidump = MinidumpProcess { data: Vec::new(), ranges: Vec::new(), main_module_base: 0, main_module_size: 0 };
+
+        for i in 0..stream_count
+        {
+            let entry = directory_rva + i * 12;
+            let stream_type = read_u32(&data, entry).ok_or("Invalid stream directory")?;
+            let rva = read_u32(&data, entry + 8).ok_or("Invalid stream directory")? as usize;
+
+            match stream_type
+            {
+                MODULE_LIST_STREAM =>
+                {
+                    let module_count = read_u32(&data, rva).ok_or("Invalid module list")?;
+                    if module_count > 0
+                    {
+                        minidump.main_module_base = read_u64(&data, rva + 4).ok_or("Invalid module list")? as usize;
+                        minidump.main_module_size = read_u32(&data, rva + 12).ok_or("Invalid module list")? as usize;
+                    }
+                }
+                MEMORY_LIST_STREAM =>
+                {
+                    let range_count = read_u32(&data, rva).ok_or("Invalid memory list")? as usize;
+                    for r in 0..range_count
+                    {
+                        let descriptor = rva + 4 + r * 16;
+                        let start = read_u64(&data, descriptor).ok_or("Invalid memory list")? as usize;
+                        let size = read_u32(&data, descriptor + 8).ok_or("Invalid memory list")? as usize;
+                        let file_offset = read_u32(&data, descriptor + 12).ok_or("Invalid memory list")? as usize;
+                        minidump.ranges.push(MemoryRange { start, size, file_offset });
+                    }
+                }
+                MEMORY64_LIST_STREAM =>
+                {
+                    let range_count = read_u64(&data, rva).ok_or("Invalid memory64 list")? as usize;
+                    let mut file_offset = read_u64(&data, rva + 8).ok_or("Invalid memory64 list")? as usize;
+                    for r in 0..range_count
+                    {
+                        let descriptor = rva + 16 + r * 16;
+                        let start = read_u64(&data, descriptor).ok_or("Invalid memory64 list")? as usize;
+                        let size = read_u64(&data, descriptor + 8).ok_or("Invalid memory64 list")? as usize;
+                        minidump.ranges.push(MemoryRange { start, size, file_offset });
+                        file_offset = file_offset.checked_add(size).ok_or("Invalid memory64 list")?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        minidump.data = data;
+        return Ok(minidump);
+    }
+
+    /// Returns the base address of the main module in the dump
+    pub fn get_main_module_base(&self) -> usize
+    {
+        return self.main_module_base;
+    }
+
+    /// Does an absolute scan against the main module in the dump and returns the absolute address of the match plus the scan offset
+    pub fn scan_abs(&self, pattern: &str, scan_offset: usize) -> Option<usize>
+    {
+        let memory = self.get_main_module_memory()?;
+        let address = scan(&memory, &to_pattern(pattern))?;
+        return Some(self.main_module_base + address + scan_offset);
+    }
+
+    /// Does a relative scan against the main module in the dump and returns the absolute address the instruction points to
+    pub fn scan_rel(&self, pattern: &str, scan_offset: usize, instruction_size: usize) -> Option<usize>
+    {
+        let memory = self.get_main_module_memory()?;
+        let address = scan(&memory, &to_pattern(pattern))?;
+        let displacement = i32::from_le_bytes(memory.get(address + scan_offset..address + scan_offset + 4)?.try_into().unwrap());
+        return Some((self.main_module_base + address + instruction_size).wrapping_add_signed(displacement as isize));
+    }
+
+    /// Resolve a pointer path against the dump the same way a Pointer would and read a u32 at the end of it.
+    /// All offsets but the last are dereferenced as 64 bit pointers.
+    pub fn read_u32_abs_chain(&self, address: usize, offsets: &[usize]) -> Option<u32>
+    {
+        let mut ptr = address;
+        for i in 0..offsets.len()
+        {
+            ptr += offsets[i];
+            if i + 1 < offsets.len()
+            {
+                let mut buffer = [0; 8];
+                if !self.read_memory_abs(ptr, &mut buffer)
+                {
+                    return None;
+                }
+                ptr = u64::from_le_bytes(buffer) as usize;
+                if ptr == 0
+                {
+                    return None;
+                }
+            }
+        }
+
+        let mut buffer = [0; 4];
+        if !self.read_memory_abs(ptr, &mut buffer)
+        {
+            return None;
+        }
+        return Some(u32::from_le_bytes(buffer));
+    }
+
+    /// Creates a buffer-backed process from the dump (see Process::new_from_buffer), so that scans, create_pointer and pointers work against it.
+    /// The main module becomes the main module of the process, all other memory in the dump is added as buffer regions.
+    /// Adjacent memory ranges in the dump are merged, so reads can span them. Writes go to the copy, not to the dump.
+    pub fn to_process(&self) -> Process
+    {
+        let memory = self.get_main_module_memory().unwrap_or_default();
+        let mut process = Process::new_from_buffer(memory, self.main_module_base);
+
+        let mut ranges: Vec<&MemoryRange> = self.ranges.iter().collect();
+        ranges.sort_by_key(|range| range.start);
+
+        let mut region: Option<(usize, Vec<u8>)> = None;
+        for range in ranges
+        {
+            let bytes = match range.file_offset.checked_add(range.size).and_then(|end| self.data.get(range.file_offset..end))
+            {
+                Some(bytes) => bytes,
+                None => continue,
+            };
+
+            match region.as_mut()
+            {
+                Some((start, memory)) if start.checked_add(memory.len()) == Some(range.start) => memory.extend_from_slice(bytes),
+                _ =>
+                {
+                    if let Some((start, memory)) = region.take()
+                    {
+                        process.add_buffer_region(start, memory);
+                    }
+                    region = Some((range.start, bytes.to_vec()));
+                }
+            }
+        }
+        if let Some((start, memory)) = region
+        {
+            process.add_buffer_region(start, memory);
+        }
+        return process;
+    }
+
+    fn get_main_module_memory(&self) -> Option<Vec<u8>>
+    {
+        let mut memory = vec![0; self.main_module_size];
+        if self.main_module_size == 0 || !self.read_memory_abs(self.main_module_base, &mut memory)
+        {
+            return None;
+        }
+        return Some(memory);
+    }
+}
+
+impl BaseReadWrite for MinidumpProcess
+{
+    fn read_memory_rel(&self, offset: Option<usize>, buffer: &mut [u8]) -> bool
+    {
+        let mut address = self.main_module_base;
+        if offset.is_some()
+        {
+            address += offset.unwrap();
+        }
+        return self.read_memory_abs(address, buffer);
+    }
+
+    fn write_memory_rel(&self, _offset: Option<usize>, _buffer: &[u8]) -> bool
+    {
+        return false;
+    }
+
+    fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        //A read can span multiple adjacent ranges
+        let mut copied = 0;
+        while copied < buffer.len()
+        {
+            let current = match address.checked_add(copied)
+            {
+                Some(current) => current,
+                None => return false,
+            };
+            let range = self.ranges.iter().find(|r| current >= r.start && r.start.checked_add(r.size).is_some_and(|end| current < end));
+            if range.is_none()
+            {
+                return false;
+            }
+            let range = range.unwrap();
+
+            let range_offset = current - range.start;
+            let len = usize::min(range.size - range_offset, buffer.len() - copied);
+            let file_offset = match range.file_offset.checked_add(range_offset)
+            {
+                Some(file_offset) if file_offset.checked_add(len).is_some_and(|end| end <= self.data.len()) => file_offset,
+                _ => return false,
+            };
+
+            buffer[copied..copied + len].copy_from_slice(&self.data[file_offset..file_offset + len]);
+            copied += len;
+        }
+        return true;
+    }
+
+    fn write_memory_abs(&self, _address: usize, _buffer: &[u8]) -> bool
+    {
+        return false;
+    }
+}
+
+impl ReadWrite for MinidumpProcess{}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32>
+{
+    return Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().unwrap()));
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64>
+{
+    return Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().unwrap()));
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const MAIN_MODULE: usize = 0x140000000;
+    const HEAP: usize = 0x20000000;
+
+    //Builds a minidump with a module list and a memory list containing the main module and a heap range
+    fn build_minidump(main_module: &[u8], heap: &[u8]) -> Vec<u8>
+    {
+        let mut data = Vec::new();
+        let module_list = 32 + 2 * 12;
+        let memory_list = module_list + 4 + 108;
+        let main_module_rva = memory_list + 4 + 2 * 16;
+        let heap_rva = main_module_rva + main_module.len();
+
+        //Header
+        data.extend_from_slice(&MINIDUMP_SIGNATURE.to_le_bytes());
+        data.extend_from_slice(&[0u8; 4]);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&32u32.to_le_bytes());
+        data.resize(32, 0);
+
+        //Stream directory
+        for (stream_type, rva) in [(MODULE_LIST_STREAM, module_list), (MEMORY_LIST_STREAM, memory_list)]
+        {
+            data.extend_from_slice(&stream_type.to_le_bytes());
+            data.extend_from_slice(&0u32.to_le_bytes());
+            data.extend_from_slice(&(rva as u32).to_le_bytes());
+        }
+
+        //Module list with only the main module
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&(MAIN_MODULE as u64).to_le_bytes());
+        data.extend_from_slice(&(main_module.len() as u32).to_le_bytes());
+        data.resize(memory_list, 0);
+
+        //Memory list
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for (start, memory, rva) in [(MAIN_MODULE, main_module, main_module_rva), (HEAP, heap, heap_rva)]
+        {
+            data.extend_from_slice(&(start as u64).to_le_bytes());
+            data.extend_from_slice(&(memory.len() as u32).to_le_bytes());
+            data.extend_from_slice(&(rva as u32).to_le_bytes());
+        }
+        data.extend_from_slice(main_module);
+        data.extend_from_slice(heap);
+        return data;
+    }
+
+    #[test]
+    fn pointers_resolve_against_a_dump()
+    {
+        let mut main_module = vec![0u8; 0x10];
+        main_module[0..8].copy_from_slice(&(HEAP as u64).to_le_bytes());
+        let mut heap = vec![0u8; 8];
+        heap[4..8].copy_from_slice(&1234u32.to_le_bytes());
+
+        let dump = MinidumpProcess::from_bytes(build_minidump(&main_module, &heap)).unwrap();
+        assert_eq!(dump.read_u32_abs(HEAP + 4), 1234);
+
+        let process = dump.to_process();
+        let pointer = process.create_pointer(MAIN_MODULE, vec![0, 4]);
+        assert_eq!(pointer.read_u32_rel(None), 1234);
+    }
+
+    #[test]
+    fn reads_outside_the_dump_fail()
+    {
+        let dump = MinidumpProcess::from_bytes(build_minidump(&[0u8; 0x10], &[0u8; 8])).unwrap();
+        let mut buffer = [0u8; 4];
+        assert!(!dump.read_memory_abs(usize::MAX - 1, &mut buffer));
+        assert!(!dump.read_memory_abs(HEAP + 6, &mut buffer));
+    }
+}
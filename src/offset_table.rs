@@ -0,0 +1,82 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+/// A table of named offsets, so that pointer paths can be defined by name and only the numbers change between game versions.
+/// With the serde feature enabled, an OffsetTable can be deserialized from any format, such as toml or json, as a map of names to offsets.
+/// With the toml feature enabled, load_from_str reads it from toml.
+///
+/// # Examples
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let table = OffsetTable::load_from_str(r#"
+///     # Dark Souls Remastered
+///     player_ins = 0x68
+///     player_game_data = 0x578
+///     health = 0x3e8
+/// "#)?;
+///
+/// let mut process = Process::new("name_of_process.exe");
+/// process.refresh()?;
+/// let health = process.create_named_pointer(0x141c04a08, &["player_ins", "player_game_data", "health"], &table)?;
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct OffsetTable
+{
+    pub offsets: HashMap<String, usize>,
+}
+
+impl OffsetTable
+{
+    /// Parses an offset table from toml, with one name = offset pair per offset. Offsets can be decimal or hexadecimal with a 0x prefix.
+    #[cfg(feature = "toml")]
+    pub fn load_from_str(str: &str) -> Result<OffsetTable, String>
+    {
+        return toml::from_str(str).map_err(|e| e.to_string());
+    }
+
+    pub fn insert(&mut self, name: &str, offset: usize)
+    {
+        self.offsets.insert(String::from(name), offset);
+    }
+
+    pub fn get(&self, name: &str) -> Option<usize>
+    {
+        return self.offsets.get(name).copied();
+    }
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn loads_offsets_from_toml()
+    {
+        let table = OffsetTable::load_from_str("# comment\nplayer_ins = 0x68\nhealth = 1000\n").unwrap();
+        assert_eq!(table.get("player_ins"), Some(0x68));
+        assert_eq!(table.get("health"), Some(1000));
+        assert_eq!(table.get("stamina"), None);
+
+        assert!(OffsetTable::load_from_str("health = \"a lot\"").is_err());
+    }
+}
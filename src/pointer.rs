@@ -15,9 +15,16 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
-use crate::read_write::{BaseReadWrite, ReadWrite};
+use log::debug;
+use smallvec::SmallVec;
+use crate::read_write::{BaseReadWrite, Pod, ReadWrite};
 use crate::process_data::ProcessData;
+use crate::resolved_pointer::ResolvedPointer;
+
+//Offsets are stored inline for short pointer paths, to avoid a heap allocation per pointer and per read
+type Offsets = SmallVec<[usize; 8]>;
 
 
 /// Represents a pointer path that is dynamically resolved each read/write operation.
@@ -34,13 +41,17 @@ use crate::process_data::ProcessData;
 /// let pointer = process.create_pointer(0x1234, vec![0]);
 /// let data = pointer.read_u8_rel(Some(0x1234));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pointer
 {
+    #[cfg_attr(feature = "serde", serde(skip))]
     process_data: Rc<RefCell<ProcessData>>,
     is_64_bit: bool,
     base_address: usize,
-    offsets: Vec<usize>,
-    /// Set this to true to print each memory address while resolving the pointer path.
+    offsets: Offsets,
+    #[cfg_attr(feature = "serde", serde(default))]
+    offset_names: Vec<String>,
+    /// Set this to true to log each memory address while resolving the pointer path, using the log crate at debug level.
     pub debug: bool,
 }
 
@@ -53,7 +64,8 @@ impl Default for Pointer
             process_data: Rc::new(RefCell::new(ProcessData::default())),
             is_64_bit: true,
             base_address: 0,
-            offsets: Vec::new(),
+            offsets: Offsets::new(),
+            offset_names: Vec::new(),
             debug: false,
         }
     }
@@ -68,18 +80,547 @@ impl Pointer
             process_data,
             is_64_bit,
             base_address,
-            offsets,
+            offsets: Offsets::from_vec(offsets),
+            offset_names: Vec::new(),
+            debug: false,
+        }
+    }
+
+    pub(crate) fn new_from_slice(process_data: Rc<RefCell<ProcessData>>, is_64_bit: bool, base_address: usize, offsets: &[usize]) -> Self
+    {
+        Pointer
+        {
+            process_data,
+            is_64_bit,
+            base_address,
+            offsets: Offsets::from_slice(offsets),
+            offset_names: Vec::new(),
             debug: false,
         }
     }
 
+    pub(crate) fn set_offset_names(&mut self, offset_names: Vec<String>)
+    {
+        self.offset_names = offset_names;
+    }
+
+    pub(crate) fn set_process_data(&mut self, process_data: Rc<RefCell<ProcessData>>)
+    {
+        self.process_data = process_data;
+    }
+
     /// Get the base address of this pointer, without resolving offsets.
     pub fn get_base_address(&self) -> usize
     {
         return self.base_address;
     }
 
-    fn resolve_offsets(&self, offsets: &Vec<usize>) -> usize
+    /// Resolve the pointer path and return the address at each step: the base address, each dereferenced pointer
+    /// and the final address. Returns None when a null pointer is encountered along the path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0x10, 0x20]);
+    /// if let Some(path) = pointer.resolve_path()
+    /// {
+    ///     for address in path
+    ///     {
+    ///         println!("{:#010x}", address);
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_path(&self) -> Option<Vec<usize>>
+    {
+        let mut path = vec![self.base_address];
+        let mut ptr = self.base_address;
+
+        for i in 0..self.offsets.len()
+        {
+            let address = ptr + self.offsets[i];
+
+            //Not the last offset = resolve as pointer
+            if i + 1 < self.offsets.len()
+            {
+                if self.is_64_bit
+                {
+                    let mut buffer = [0; 8];
+                    self.read_memory_abs(address, &mut buffer);
+                    ptr = u64::from_le_bytes(buffer) as usize;
+                }
+                else
+                {
+                    let mut buffer = [0; 4];
+                    self.read_memory_abs(address, &mut buffer);
+                    ptr = u32::from_le_bytes(buffer) as usize;
+                }
+
+                if ptr == 0
+                {
+                    return None;
+                }
+            }
+            else
+            {
+                ptr = address;
+            }
+            path.push(ptr);
+        }
+        return Some(path);
+    }
+
+    /// Resolve both pointer paths and return if they currently point at the same address, even when their offsets differ.
+    /// Returns false when either path encounters a null pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let a = process.create_pointer(0x1234, vec![0x10, 0x20]);
+    /// let b = process.create_pointer(0x5678, vec![0x0, 0x20]);
+    /// let same = a.resolves_same_as(&b);
+    /// ```
+    pub fn resolves_same_as(&self, other: &Pointer) -> bool
+    {
+        let address = self.resolve_path().and_then(|path| path.last().copied());
+        let other_address = other.resolve_path().and_then(|path| path.last().copied());
+        return match (address, other_address)
+        {
+            (Some(a), Some(b)) => a != 0 && a == b,
+            _ => false,
+        };
+    }
+
+    /// Resolve the pointer path once and return a ResolvedPointer that reads and writes relative to the resolved address,
+    /// without walking the pointer path again. Offsets passed to the ResolvedPointer behave the same as offsets passed to this pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0x10, 0x20]);
+    /// let resolved = pointer.resolve_cached();
+    /// let health = resolved.read_u32_rel(Some(0x40));
+    /// ```
+    pub fn resolve_cached(&self) -> ResolvedPointer
+    {
+        let mut copy = self.offsets.clone();
+        copy.push(0);
+        let address = self.resolve_offsets(&copy);
+        return ResolvedPointer::new(self.process_data.clone(), address);
+    }
+
+    /// Resolve the pointer path once and call the closure with a ResolvedPointer for the resolved address, for reading many fields
+    /// of one struct without walking the pointer path for each read. The ResolvedPointer can't outlive the closure, so it can't become stale.
+    /// Returns None without calling the closure when a null pointer is encountered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0x10, 0x20]);
+    /// let stats = pointer.with_cache(|resolved| (resolved.read_u32_rel(Some(0x40)), resolved.read_u32_rel(Some(0x44))));
+    /// ```
+    pub fn with_cache<R>(&self, f: impl FnOnce(&ResolvedPointer) -> R) -> Option<R>
+    {
+        let resolved = self.resolve_cached();
+        if resolved.get_address() == 0
+        {
+            return None;
+        }
+        return Some(f(&resolved));
+    }
+
+    /// Read a list of pointers, such as an array of entity pointers. Reads a u32 count at count_offset and a pointer to the
+    /// array at array_offset, then creates a pointer for each element at array + i * stride. Reading relative to the
+    /// returned pointers reads relative to the object each element points to.
+    /// The count comes from the target's memory, so a count above max_count is treated as garbage and returns an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let entity_list = process.create_pointer(0x1234, vec![0]);
+    /// for entity in entity_list.read_pointer_list_rel(0x8, 0x10, 0x8, 1024)
+    /// {
+    ///     let health = entity.read_u32_rel(Some(0x3e8));
+    /// }
+    /// ```
+    pub fn read_pointer_list_rel(&self, count_offset: usize, array_offset: usize, stride: usize, max_count: usize) -> Vec<Pointer>
+    {
+        let count = self.read_u32_rel(Some(count_offset)) as usize;
+        let array_base = if self.is_64_bit { self.read_u64_rel(Some(array_offset)) as usize } else { self.read_u32_rel(Some(array_offset)) as usize };
+
+        let mut result = Vec::new();
+        if array_base == 0 || count > max_count
+        {
+            return result;
+        }
+
+        for i in 0..count
+        {
+            result.push(Pointer::new(self.process_data.clone(), self.is_64_bit, array_base + i * stride, vec![0]));
+        }
+        return result;
+    }
+
+    /// Read a u32 through this pointer's path extended with additional offsets, for offset chains that are only known at runtime.
+    /// Returns None when a null pointer is encountered or the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let data = pointer.read_u32_with_offsets(&[0x10, 0x28]);
+    /// ```
+    pub fn read_u32_with_offsets(&self, offsets: &[usize]) -> Option<u32>
+    {
+        let mut copy = self.offsets.clone();
+        copy.extend_from_slice(offsets);
+        let address = self.resolve_offsets(&copy);
+        if address == 0
+        {
+            return None;
+        }
+
+        let mut buffer = [0; 4];
+        if !self.read_memory_abs(address, &mut buffer)
+        {
+            return None;
+        }
+        return Some(u32::from_le_bytes(buffer));
+    }
+
+    /// Write a u32 through this pointer's path extended with additional offsets, for offset chains that are only known at runtime.
+    /// Returns if the write succeeded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let success = pointer.write_u32_with_offsets(&[0x10, 0x28], 10);
+    /// ```
+    pub fn write_u32_with_offsets(&self, offsets: &[usize], value: u32) -> bool
+    {
+        let mut copy = self.offsets.clone();
+        copy.extend_from_slice(offsets);
+        let address = self.resolve_offsets(&copy);
+        if address == 0
+        {
+            return false;
+        }
+        return self.write_memory_abs(address, &value.to_le_bytes());
+    }
+
+    /// Relatively read a pointer of the given width (4 or 8 bytes) from an optional offset and zero-extend it to usize.
+    /// For pointer fields whose width differs from the bitness of the process. Returns None for other widths or when the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let compressed = pointer.read_ptr_width_rel(Some(0x10), 4);
+    /// ```
+    pub fn read_ptr_width_rel(&self, offset: Option<usize>, width_bytes: usize) -> Option<usize>
+    {
+        match width_bytes
+        {
+            4 =>
+            {
+                let mut buffer = [0; 4];
+                if !self.read_memory_rel(offset, &mut buffer)
+                {
+                    return None;
+                }
+                return Some(u32::from_le_bytes(buffer) as usize);
+            }
+            8 =>
+            {
+                let mut buffer = [0; 8];
+                if !self.read_memory_rel(offset, &mut buffer)
+                {
+                    return None;
+                }
+                return Some(u64::from_le_bytes(buffer) as usize);
+            }
+            _ => return None,
+        }
+    }
+
+    /// Decode a RIP-relative operand at the resolved address + offset: reads the signed 4-byte displacement there
+    /// and returns address + offset + instruction_size + displacement. Returns None when a null pointer is encountered or the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let target = pointer.read_rip_relative_rel(0x3, 7);
+    /// ```
+    pub fn read_rip_relative_rel(&self, offset: usize, instruction_size: usize) -> Option<usize>
+    {
+        let mut copy = self.offsets.clone();
+        copy.push(offset);
+        let address = self.resolve_offsets(&copy);
+        if address == 0
+        {
+            return None;
+        }
+
+        let mut buffer = [0; 4];
+        if !self.read_memory_abs(address, &mut buffer)
+        {
+            return None;
+        }
+        let displacement = i32::from_le_bytes(buffer) as isize;
+        return Some((address + instruction_size).wrapping_add_signed(displacement));
+    }
+
+    /// Relatively read an Unreal Engine TArray from an optional offset: a data pointer followed by the i32 ArrayNum and ArrayMax.
+    /// Reads ArrayNum elements of T from the data pointer, see Pod for the requirements on T.
+    /// Returns an empty vec when the read fails or the header is not a valid TArray. ArrayNum comes from the target's memory,
+    /// so a torn or uninitialized header with more than max_elements elements is treated as invalid instead of allocating for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let actors: Vec<u64> = pointer.read_tarray_rel(Some(0x98), 4096);
+    /// ```
+    pub fn read_tarray_rel<T: Pod>(&self, offset: Option<usize>, max_elements: usize) -> Vec<T>
+    {
+        let pointer_size = if self.is_64_bit { 8 } else { 4 };
+        let mut header = vec![0u8; pointer_size + 8];
+        if !self.read_memory_rel(offset, &mut header)
+        {
+            return Vec::new();
+        }
+
+        let data = if self.is_64_bit
+        {
+            u64::from_le_bytes(header[0..8].try_into().unwrap()) as usize
+        }
+        else
+        {
+            u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize
+        };
+        let num = i32::from_le_bytes(header[pointer_size..pointer_size + 4].try_into().unwrap());
+        let max = i32::from_le_bytes(header[pointer_size + 4..pointer_size + 8].try_into().unwrap());
+
+        if data == 0 || num <= 0 || num > max || num as usize > max_elements
+        {
+            return Vec::new();
+        }
+
+        let element_size = std::mem::size_of::<T>();
+        let mut buffer = vec![0u8; num as usize * element_size];
+        if !self.read_memory_abs(data, &mut buffer)
+        {
+            return Vec::new();
+        }
+
+        let mut result = Vec::with_capacity(num as usize);
+        for i in 0..num as usize
+        {
+            result.push(unsafe { std::ptr::read_unaligned(buffer[i * element_size..].as_ptr() as *const T) });
+        }
+        return result;
+    }
+
+    /// Relatively read an Unreal Engine FName from an optional offset, returning its (ComparisonIndex, Number) pair.
+    /// Resolving the index to a string depends on the engine version's name pool and is left to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let name = pointer.read_fname_rel(Some(0x18));
+    /// ```
+    pub fn read_fname_rel(&self, offset: Option<usize>) -> Option<(i32, i32)>
+    {
+        let mut buffer = [0; 8];
+        if !self.read_memory_rel(offset, &mut buffer)
+        {
+            return None;
+        }
+        let index = i32::from_le_bytes(buffer[0..4].try_into().unwrap());
+        let number = i32::from_le_bytes(buffer[4..8].try_into().unwrap());
+        return Some((index, number));
+    }
+
+    /// Resolve a virtual function: reads the object pointer at the resolved address + object_offset, the vtable pointer at the start
+    /// of the object, and the function pointer at vtable + vtable_index * pointer size. Returns None when a null pointer is encountered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let function = pointer.read_vfunc_rel(0x10, 3);
+    /// ```
+    pub fn read_vfunc_rel(&self, object_offset: usize, vtable_index: usize) -> Option<usize>
+    {
+        let pointer_size = if self.is_64_bit { 8 } else { 4 };
+        let read_pointer = |address: usize|
+        {
+            let mut buffer = [0u8; 8];
+            if address == 0 || !self.read_memory_abs(address, &mut buffer[..pointer_size])
+            {
+                return None;
+            }
+            match u64::from_le_bytes(buffer) as usize
+            {
+                0 => None,
+                pointer => Some(pointer),
+            }
+        };
+
+        let object = self.read_ptr_width_rel(Some(object_offset), pointer_size).filter(|object| *object != 0)?;
+        let vtable = read_pointer(object)?;
+        return read_pointer(vtable + vtable_index * pointer_size);
+    }
+
+    /// Walk a linked list starting at the resolved address, following the pointer at node + next_offset up to max nodes.
+    /// Stops on a null pointer or when a node is visited twice, such as when a circular list returns to its head.
+    /// Returns the address of each node, starting with the resolved address itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// for node in pointer.iter_linked_list_rel(0x0, 1000)
+    /// {
+    ///     println!("{:#x}", node);
+    /// }
+    /// ```
+    pub fn iter_linked_list_rel(&self, next_offset: usize, max: usize) -> Vec<usize>
+    {
+        let mut copy = self.offsets.clone();
+        copy.push(0);
+        let mut node = self.resolve_offsets(&copy);
+
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+        while node != 0 && result.len() < max && visited.insert(node)
+        {
+            result.push(node);
+
+            node = if self.is_64_bit
+            {
+                let mut buffer = [0; 8];
+                if !self.read_memory_abs(node + next_offset, &mut buffer)
+                {
+                    break;
+                }
+                u64::from_le_bytes(buffer) as usize
+            }
+            else
+            {
+                let mut buffer = [0; 4];
+                if !self.read_memory_abs(node + next_offset, &mut buffer)
+                {
+                    break;
+                }
+                u32::from_le_bytes(buffer) as usize
+            };
+        }
+        return result;
+    }
+
+    /// Relatively read a table of count fixed-width strings from an optional offset, where string i starts at offset + i * stride.
+    /// Each string is at most max_len characters and ends at the first null terminator. When wide is true the strings are read as utf-16.
+    /// The whole table is read at once. Returns an empty vec when the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    /// let names = pointer.read_string_array_rel(Some(0x10), 16, 32, 32, false);
+    /// ```
+    pub fn read_string_array_rel(&self, offset: Option<usize>, count: usize, stride: usize, max_len: usize, wide: bool) -> Vec<String>
+    {
+        let mut result = Vec::new();
+        if count == 0
+        {
+            return result;
+        }
+
+        let char_size = if wide { 2 } else { 1 };
+        let entry_size = max_len * char_size;
+        let mut buffer = vec![0u8; (count - 1) * stride + entry_size];
+        if !self.read_memory_rel(offset, &mut buffer)
+        {
+            return result;
+        }
+
+        for i in 0..count
+        {
+            let entry = &buffer[i * stride..i * stride + entry_size];
+            if wide
+            {
+                let chars: Vec<u16> = entry.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).take_while(|c| *c != 0).collect();
+                result.push(String::from_utf16_lossy(&chars));
+            }
+            else
+            {
+                let length = entry.iter().position(|b| *b == 0).unwrap_or(entry.len());
+                result.push(String::from_utf8_lossy(&entry[..length]).into_owned());
+            }
+        }
+        return result;
+    }
+
+    fn resolve_offsets(&self, offsets: &[usize]) -> usize
     {
         let mut path = String::from(format!(" {:#010x}", self.base_address));
         let mut ptr = self.base_address;
@@ -90,6 +631,7 @@ impl Pointer
 
             //Create a copy for debug output
             let debug_copy = ptr;
+            let name = self.offset_names.get(i).map(|name| format!(" ({})", name)).unwrap_or_default();
 
             //Resolve an offset
             let address = ptr + offset;
@@ -101,22 +643,22 @@ impl Pointer
                 {
                     let mut buffer = [0; 8];
                     self.read_memory_abs(address, &mut buffer);
-                    ptr = u64::from_ne_bytes(buffer) as usize;
+                    ptr = u64::from_le_bytes(buffer) as usize;
                 }
                 else
                 {
                     let mut buffer = [0; 4];
                     self.read_memory_abs(address, &mut buffer);
-                    ptr = u32::from_ne_bytes(buffer) as usize;
+                    ptr = u32::from_le_bytes(buffer) as usize;
                 }
 
-                path.push_str(format!("\n[{:#010x} + {:#010x}]: {:#010x}", debug_copy, offset, ptr).as_str());
+                path.push_str(format!("\n[{:#010x} + {:#010x}{}]: {:#010x}", debug_copy, offset, name, ptr).as_str());
 
                 if ptr == 0
                 {
                     if self.debug
                     {
-                        println!("{}", path);
+                        debug!("{}", path);
                     }
                     return 0;
                 }
@@ -124,12 +666,12 @@ impl Pointer
             else
             {
                 ptr = address;
-                path.push_str(format!("\n{:#010x} + {:#010x}: {:#010x}", debug_copy, offset, ptr).as_str());
+                path.push_str(format!("\n{:#010x} + {:#010x}{}: {:#010x}", debug_copy, offset, name, ptr).as_str());
             }
         }
         if self.debug
         {
-            println!("{}", path);
+            debug!("{}", path);
         }
         return ptr;
     }
@@ -145,7 +687,7 @@ impl BaseReadWrite for Pointer
             copy.push(offset.unwrap());
         }
         let address = self.resolve_offsets(&copy);
-        return self.read_with_handle(self.process_data.borrow().handle, address, buffer);
+        return self.read_memory_abs(address, buffer);
     }
 
     fn write_memory_rel(&self, offset: Option<usize>, buffer: &[u8]) -> bool
@@ -156,17 +698,46 @@ impl BaseReadWrite for Pointer
             copy.push(offset.unwrap());
         }
         let address = self.resolve_offsets(&copy);
-        return self.write_with_handle(self.process_data.borrow().handle, address, buffer);
+        return self.write_memory_abs(address, buffer);
     }
 
     fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
     {
-        return self.read_with_handle(self.process_data.borrow().handle, address, buffer);
+        if self.process_data.borrow().from_buffer
+        {
+            return self.process_data.borrow().read_buffer(address, buffer);
+        }
+
+        let handle = self.process_data.borrow().handle.raw();
+        if self.read_with_handle(handle, address, buffer)
+        {
+            return true;
+        }
+
+        //Detach when the read failed because the process exited. Skipped while the process data is borrowed, refresh detaches it later
+        if let Ok(mut process_data) = self.process_data.try_borrow_mut()
+        {
+            process_data.detach_if_exited();
+        }
+        return false;
     }
 
     fn write_memory_abs(&self, address: usize, buffer: &[u8]) -> bool
     {
-        return self.write_with_handle(self.process_data.borrow().handle, address, buffer);
+        if self.process_data.borrow().dry_run_write(address, buffer)
+        {
+            return true;
+        }
+        if self.process_data.borrow().reject_read_only_write(address)
+        {
+            return false;
+        }
+        if self.process_data.borrow().from_buffer
+        {
+            //Fails instead of panicking while the process data is borrowed, for example through try_get_main_module
+            return self.process_data.try_borrow_mut().is_ok_and(|mut process_data| process_data.write_buffer(address, buffer));
+        }
+        return self.write_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
     }
 }
 
@@ -0,0 +1,60 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_ACCESS_RIGHTS, PROCESS_VM_OPERATION, PROCESS_VM_WRITE};
+use crate::prelude::Process;
+use crate::process_handle::ProcessHandle;
+
+impl Process
+{
+    /// Re-opens the handle to the attached process with different access rights, closing the old handle.
+    /// Keeps the attached state, cached modules and pointers intact, unlike a full re-attach.
+    /// The process is read-only afterwards when the rights don't allow writing, see is_read_only. The rights are kept for later re-attaches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    /// use windows::Win32::System::Threading::{PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE};
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.upgrade_access(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ | PROCESS_VM_WRITE | PROCESS_VM_OPERATION)?;
+    /// ```
+    pub fn upgrade_access(&mut self, rights: PROCESS_ACCESS_RIGHTS) -> Result<(), String>
+    {
+        if !self.is_attached()
+        {
+            return Err(String::from("Process not attached"));
+        }
+
+        unsafe
+        {
+            let mut process_data = self.process_data.borrow_mut();
+            match OpenProcess(rights, false, process_data.id)
+            {
+                Ok(handle) =>
+                {
+                    process_data.handle = ProcessHandle::new(handle);
+                    process_data.read_only = !rights.contains(PROCESS_VM_WRITE | PROCESS_VM_OPERATION);
+                    process_data.access_rights = rights;
+                    return Ok(());
+                }
+                Err(_) => return Err(String::from("Failed to open process with the requested access rights")),
+            }
+        }
+    }
+}
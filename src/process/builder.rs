@@ -0,0 +1,173 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::time::Duration;
+use windows::Win32::System::Threading::PROCESS_ACCESS_RIGHTS;
+use crate::prelude::Process;
+use crate::process_data::{DEFAULT_ACCESS_RIGHTS, DEFAULT_REFRESH_INTERVAL};
+
+/// Configures a process before it attaches. For the common case of attaching by name, use Process::new.
+///
+/// # Examples
+///
+/// ```
+/// use mem_rs::prelude::*;
+/// use windows::Win32::System::Threading::{PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
+///
+/// let mut process = ProcessBuilder::new()
+///     .name("name_of_process.exe")
+///     .access_rights(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ)
+///     .dump_main_module(false)
+///     .build();
+/// process.refresh()?;
+///
+/// let mut process = ProcessBuilder::new()
+///     .window_title("DARK SOULS", true)
+///     .build();
+/// process.refresh()?;
+/// ```
+pub struct ProcessBuilder
+{
+    name: String,
+    access_rights: PROCESS_ACCESS_RIGHTS,
+    dump_main_module: bool,
+    match_full_path: bool,
+    refresh_interval: Duration,
+    dry_run: bool,
+    redump_on_refresh: bool,
+    pid: Option<u32>,
+    window_title: Option<String>,
+    match_window_substring: bool,
+}
+
+impl Default for ProcessBuilder
+{
+    fn default() -> Self
+    {
+        ProcessBuilder
+        {
+            name: String::new(),
+            access_rights: DEFAULT_ACCESS_RIGHTS,
+            dump_main_module: true,
+            match_full_path: false,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            dry_run: false,
+            redump_on_refresh: false,
+            pid: None,
+            window_title: None,
+            match_window_substring: false,
+        }
+    }
+}
+
+impl ProcessBuilder
+{
+    pub fn new() -> Self
+    {
+        return ProcessBuilder::default();
+    }
+
+    /// The name of the process to attach to, such as name_of_process.exe
+    pub fn name(mut self, name: &str) -> Self
+    {
+        self.name = String::from(name);
+        return self;
+    }
+
+    /// Attach to the process with this pid instead of searching by name, see Process::attach_pid.
+    /// Only the first attach uses the pid, once that process exits refresh searches by name again.
+    pub fn pid(mut self, pid: u32) -> Self
+    {
+        self.pid = Some(pid);
+        return self;
+    }
+
+    /// Attach to the process that owns the window with this title instead of searching by name, see Process::attach_by_window_title
+    pub fn window_title(mut self, title: &str, match_substring: bool) -> Self
+    {
+        self.window_title = Some(String::from(title));
+        self.match_window_substring = match_substring;
+        return self;
+    }
+
+    /// The access rights to open the process with. When opening fails, refresh falls back to a read-only attachment.
+    pub fn access_rights(mut self, access_rights: PROCESS_ACCESS_RIGHTS) -> Self
+    {
+        self.access_rights = access_rights;
+        return self;
+    }
+
+    /// Whether to dump the main module when attaching. Scans need the dump, skip it when only reading through known addresses.
+    pub fn dump_main_module(mut self, dump_main_module: bool) -> Self
+    {
+        self.dump_main_module = dump_main_module;
+        return self;
+    }
+
+    /// Match the name against the full path of the executable instead of only the file name,
+    /// to pick the right process when multiple executables share a name.
+    pub fn match_full_path(mut self, match_full_path: bool) -> Self
+    {
+        self.match_full_path = match_full_path;
+        return self;
+    }
+
+    /// See Process::set_refresh_interval
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self
+    {
+        self.refresh_interval = refresh_interval;
+        return self;
+    }
+
+    /// See Process::set_dry_run
+    pub fn dry_run(mut self, dry_run: bool) -> Self
+    {
+        self.dry_run = dry_run;
+        return self;
+    }
+
+    /// See Process::set_redump_on_refresh
+    pub fn redump_on_refresh(mut self, redump_on_refresh: bool) -> Self
+    {
+        self.redump_on_refresh = redump_on_refresh;
+        return self;
+    }
+
+    /// Creates the process. The process is not attached yet, call refresh to attach.
+    /// When a pid or window title is set, refresh attaches through those instead of searching by name.
+    pub fn build(self) -> Process
+    {
+        let process = Process::new(&self.name);
+        {
+            let mut process_data = process.process_data.borrow_mut();
+            //Process::new strips the directory from the name, keep it when matching the full path
+            if self.match_full_path
+            {
+                process_data.name = self.name;
+            }
+            process_data.access_rights = self.access_rights;
+            process_data.dump_main_module = self.dump_main_module;
+            process_data.match_full_path = self.match_full_path;
+            process_data.refresh_interval = self.refresh_interval;
+            process_data.dry_run = self.dry_run;
+            process_data.redump_on_refresh = self.redump_on_refresh;
+            process_data.target_pid = self.pid;
+            process_data.window_title = self.window_title;
+            process_data.match_window_substring = self.match_window_substring;
+        }
+        return process;
+    }
+}
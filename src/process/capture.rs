@@ -0,0 +1,51 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+impl Process
+{
+    /// Reads size bytes at each of the given absolute addresses. Addresses that can't be read are left out.
+    /// Use together with helpers::diff_addresses to find the addresses that changed between two captures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    /// use mem_rs::helpers::diff_addresses;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let candidates = process.scan_predicate_u32(|value| value == 100);
+    /// let before = process.capture_values(&candidates, 4);
+    /// //Do something in game
+    /// let after = process.capture_values(&candidates, 4);
+    /// let changed = diff_addresses(&before, &after);
+    /// ```
+    pub fn capture_values(&self, addresses: &[usize], size: usize) -> Vec<(usize, Vec<u8>)>
+    {
+        let mut result = Vec::new();
+        for address in addresses
+        {
+            let mut buffer = vec![0; size];
+            if self.read_memory_abs(*address, &mut buffer)
+            {
+                result.push((*address, buffer));
+            }
+        }
+        return result;
+    }
+}
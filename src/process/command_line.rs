@@ -0,0 +1,110 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use windows::Wdk::System::Threading::{NtQueryInformationProcess, ProcessBasicInformation};
+use windows::Win32::System::Threading::PROCESS_BASIC_INFORMATION;
+use crate::helpers::split_command_line;
+use crate::prelude::*;
+
+//Offsets of PEB.ProcessParameters and RTL_USER_PROCESS_PARAMETERS.CommandLine for the bitness of this crate
+#[cfg(target_pointer_width = "64")]
+const PROCESS_PARAMETERS_OFFSET: usize = 0x20;
+#[cfg(target_pointer_width = "64")]
+const COMMAND_LINE_OFFSET: usize = 0x70;
+#[cfg(target_pointer_width = "32")]
+const PROCESS_PARAMETERS_OFFSET: usize = 0x10;
+#[cfg(target_pointer_width = "32")]
+const COMMAND_LINE_OFFSET: usize = 0x40;
+
+impl Process
+{
+    /// Reads the command line that the attached process was started with, from its process environment block.
+    /// Returns None when not attached or when the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let command_line = process.get_command_line();
+    /// ```
+    pub fn get_command_line(&self) -> Option<String>
+    {
+        if !self.is_attached() || self.process_data.borrow().from_buffer
+        {
+            return None;
+        }
+
+        let mut info = PROCESS_BASIC_INFORMATION::default();
+        let status = unsafe
+        {
+            NtQueryInformationProcess(self.process_data.borrow().handle.raw(), ProcessBasicInformation, &mut info as *mut _ as *mut c_void, size_of::<PROCESS_BASIC_INFORMATION>() as u32, std::ptr::null_mut())
+        };
+        if status.is_err() || info.PebBaseAddress.is_null()
+        {
+            return None;
+        }
+
+        let mut buffer = [0u8; size_of::<usize>()];
+        if !self.read_memory_abs(info.PebBaseAddress as usize + PROCESS_PARAMETERS_OFFSET, &mut buffer)
+        {
+            return None;
+        }
+        let process_parameters = usize::from_le_bytes(buffer);
+
+        //UNICODE_STRING: the length in bytes followed by the buffer pointer, aligned to the pointer size
+        let mut length = [0u8; 2];
+        if !self.read_memory_abs(process_parameters + COMMAND_LINE_OFFSET, &mut length)
+            || !self.read_memory_abs(process_parameters + COMMAND_LINE_OFFSET + size_of::<usize>(), &mut buffer)
+        {
+            return None;
+        }
+
+        let mut command_line = vec![0u8; u16::from_le_bytes(length) as usize];
+        if !self.read_memory_abs(usize::from_le_bytes(buffer), &mut command_line)
+        {
+            return None;
+        }
+
+        let chars: Vec<u16> = command_line.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        return Some(String::from_utf16_lossy(&chars));
+    }
+
+    /// Reads the command line that the attached process was started with and splits it into arguments,
+    /// following the same quoting rules as CommandLineToArgvW. The first argument is the program.
+    /// Returns None when not attached or when the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(args) = process.get_command_line_args()
+    /// {
+    ///     let windowed = args.iter().any(|arg| arg == "-windowed");
+    /// }
+    /// ```
+    pub fn get_command_line_args(&self) -> Option<Vec<String>>
+    {
+        return self.get_command_line().map(|command_line| split_command_line(&command_line));
+    }
+}
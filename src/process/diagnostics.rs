@@ -0,0 +1,82 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::mem::size_of;
+use windows::Win32::System::ProcessStatus::{K32GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+use windows::Win32::System::Threading::{GetCurrentProcess, GetProcessHandleCount};
+use crate::process::Process;
+use crate::process_memory_info::ProcessMemoryInfo;
+
+impl Process
+{
+    /// Returns the number of open handles of the current process, in which this very code is running.
+    /// Useful to verify that handles aren't leaking in a polling loop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let count = Process::get_current_handle_count();
+    /// ```
+    pub fn get_current_handle_count() -> Option<u32>
+    {
+        unsafe
+        {
+            let mut count = 0u32;
+            if GetProcessHandleCount(GetCurrentProcess(), &mut count).is_err()
+            {
+                return None;
+            }
+            return Some(count);
+        }
+    }
+
+    /// Returns memory usage statistics of the attached process, such as the working set size, pagefile usage and page fault count.
+    /// Returns None when not attached or when the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(info) = process.get_memory_info()
+    /// {
+    ///     println!("working set: {} bytes", info.working_set_size);
+    /// }
+    /// ```
+    pub fn get_memory_info(&self) -> Option<ProcessMemoryInfo>
+    {
+        let process_data = self.process_data.borrow();
+        if !process_data.attached || process_data.from_buffer
+        {
+            return None;
+        }
+
+        unsafe
+        {
+            let mut counters = PROCESS_MEMORY_COUNTERS::default();
+            counters.cb = size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+            if !K32GetProcessMemoryInfo(process_data.handle.raw(), &mut counters, counters.cb).as_bool()
+            {
+                return None;
+            }
+            return Some(ProcessMemoryInfo::from(counters));
+        }
+    }
+}
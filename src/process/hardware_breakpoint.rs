@@ -0,0 +1,155 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+//Only compiled on x86_64, see process/mod.rs: the debug registers are u32 on x86 and don't exist on aarch64
+
+use windows::Win32::System::Diagnostics::Debug::CONTEXT_DEBUG_REGISTERS_AMD64;
+use crate::prelude::Process;
+
+/// The kind of access that triggers a hardware breakpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BpKind
+{
+    Execute,
+    Write,
+    ReadWrite,
+}
+
+/// The size of the memory watched by a hardware breakpoint. Execute breakpoints must use Byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BpSize
+{
+    Byte,
+    Word,
+    Dword,
+    Qword,
+}
+
+impl Process
+{
+    /// Sets a hardware breakpoint on a thread using one of the debug registers DR0-DR3.
+    /// Returns the index of the debug register that was used. The thread should be suspended.
+    /// Returns an error for an Execute breakpoint with a size other than Byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let slot = process.set_hardware_breakpoint(1234, 0x141c04a08, BpKind::Write, BpSize::Dword)?;
+    /// ```
+    pub fn set_hardware_breakpoint(&self, thread_id: u32, address: usize, kind: BpKind, size: BpSize) -> Result<usize, String>
+    {
+        if kind == BpKind::Execute && size != BpSize::Byte
+        {
+            return Err(String::from("Execute breakpoints must use BpSize::Byte"));
+        }
+
+        let mut context = self.get_thread_context(thread_id)?;
+
+        //Find a debug register that isn't locally enabled
+        let slot = (0..4).find(|i| context.Dr7 & (1 << (i * 2)) == 0);
+        if slot.is_none()
+        {
+            return Err(String::from("No free debug register"));
+        }
+        let slot = slot.unwrap();
+
+        match slot
+        {
+            0 => context.Dr0 = address as u64,
+            1 => context.Dr1 = address as u64,
+            2 => context.Dr2 = address as u64,
+            _ => context.Dr3 = address as u64,
+        }
+
+        let rw: u64 = match kind
+        {
+            BpKind::Execute => 0b00,
+            BpKind::Write => 0b01,
+            BpKind::ReadWrite => 0b11,
+        };
+
+        let len: u64 = match size
+        {
+            BpSize::Byte => 0b00,
+            BpSize::Word => 0b01,
+            BpSize::Qword => 0b10,
+            BpSize::Dword => 0b11,
+        };
+
+        //Condition and length bits for slot n are at 16 + 4n
+        let shift = 16 + slot * 4;
+        context.Dr7 &= !(0b1111 << shift);
+        context.Dr7 |= (rw | (len << 2)) << shift;
+        context.Dr7 |= 1 << (slot * 2);
+
+        context.ContextFlags = CONTEXT_DEBUG_REGISTERS_AMD64;
+        self.set_thread_context(thread_id, &context)?;
+        return Ok(slot);
+    }
+
+    /// Clears a hardware breakpoint that was set with set_hardware_breakpoint. The thread should be suspended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let slot = process.set_hardware_breakpoint(1234, 0x141c04a08, BpKind::Write, BpSize::Dword)?;
+    /// process.clear_hardware_breakpoint(1234, slot)?;
+    /// ```
+    pub fn clear_hardware_breakpoint(&self, thread_id: u32, slot: usize) -> Result<(), String>
+    {
+        if slot > 3
+        {
+            return Err(String::from("Invalid debug register"));
+        }
+
+        let mut context = self.get_thread_context(thread_id)?;
+        match slot
+        {
+            0 => context.Dr0 = 0,
+            1 => context.Dr1 = 0,
+            2 => context.Dr2 = 0,
+            _ => context.Dr3 = 0,
+        }
+
+        let shift = 16 + slot * 4;
+        context.Dr7 &= !(0b1111 << shift);
+        context.Dr7 &= !(1 << (slot * 2));
+
+        context.ContextFlags = CONTEXT_DEBUG_REGISTERS_AMD64;
+        return self.set_thread_context(thread_id, &context);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::prelude::*;
+
+    #[test]
+    fn execute_breakpoints_must_be_byte_sized()
+    {
+        let process = Process::new_from_buffer(vec![0u8; 0x10], 0x140000000);
+        assert_eq!(process.set_hardware_breakpoint(1234, 0x140000000, BpKind::Execute, BpSize::Dword), Err(String::from("Execute breakpoints must use BpSize::Byte")));
+    }
+}
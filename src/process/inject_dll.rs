@@ -16,6 +16,7 @@
 
 use std::ffi::c_void;
 use std::mem::size_of;
+use windows::Win32::Foundation::{CloseHandle, WAIT_OBJECT_0, WAIT_TIMEOUT};
 use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
 use windows::Win32::System::Memory::{MEM_COMMIT, MEM_RELEASE, MEM_RESERVE, PAGE_READWRITE, VirtualAllocEx, VirtualFreeEx};
 use windows::Win32::System::Threading::{CreateRemoteThread, OpenProcess, PROCESS_CREATE_THREAD, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE, WaitForSingleObject};
@@ -38,6 +39,30 @@ impl Process
     /// ```
     pub fn inject_dll(&self, dll_path: &str) -> Result<(), String>
     {
+        return self.inject_dll_with_timeout(dll_path, 10000);
+    }
+
+    /// Attempts to inject a dll into the attached process using LoadLibraryW, waiting at most timeout_ms milliseconds
+    /// for LoadLibraryW to return. Returns an error when the wait times out, in which case the dll may still be loading.
+    /// Returns an error in dry run mode, without touching the process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh().expect("Failed to attach/refresh!");
+    /// process.inject_dll_with_timeout(r#"C:\temp\native.dll"#, 60000).expect("Failed to inject!");
+    /// ```
+    pub fn inject_dll_with_timeout(&self, dll_path: &str, timeout_ms: u32) -> Result<(), String>
+    {
+        //Injecting allocates memory and starts a thread in the target, neither of which a dry run may do
+        if self.process_data.borrow().dry_run
+        {
+            return Err(String::from("Dry run: not injecting"));
+        }
+
         let mut path_w32_str: Vec<u16> = dll_path.encode_utf16().collect();
         path_w32_str.push(0);
 
@@ -67,7 +92,16 @@ impl Process
                     MEM_COMMIT | MEM_RESERVE,
                     PAGE_READWRITE);
 
-                self.write_memory_abs(allocated_dll_path_str as usize, &vec_u16_to_u8(&path_w32_str));
+                if allocated_dll_path_str.is_null()
+                {
+                    return Err(String::from("Failed to allocate memory for the dll path"));
+                }
+
+                if !self.write_memory_abs(allocated_dll_path_str as usize, &vec_u16_to_u8(&path_w32_str))
+                {
+                    let _ = VirtualFreeEx(process_handle, allocated_dll_path_str, 0, MEM_RELEASE);
+                    return Err(String::from("Failed to write the dll path"));
+                }
 
                 //Get a ptr to LoadLibraryW via kernel32.dll
                 let kernel32_pcwstr = get_pcwstr_from_str(&"kernel32.dll\0");
@@ -99,10 +133,62 @@ impl Process
                     return  Err(String::from("Failed to start remote thread"));
                 }
 
-                let _ = WaitForSingleObject(thread.unwrap(), 10000);
+                let thread = thread.unwrap();
+                let wait_result = WaitForSingleObject(thread, timeout_ms);
+                let _ = CloseHandle(thread);
+
+                //The remote thread may still read the path when the wait timed out, so the allocation is leaked in that case
+                if wait_result == WAIT_TIMEOUT
+                {
+                    return Err(String::from("Timed out waiting for LoadLibraryW"));
+                }
+
                 let _ = VirtualFreeEx(process_handle, allocated_dll_path_str, 0, MEM_RELEASE);
+                if wait_result != WAIT_OBJECT_0
+                {
+                    return Err(String::from("Failed to wait for remote thread"));
+                }
             }
             return Ok(());
         }
     }
+
+    /// Attempts to inject a dll into the attached process using LoadLibraryW, then writes data into a new allocation in the process
+    /// and returns its address, so that the dll can be passed configuration. The allocation is not freed. Fails in dry run mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh().expect("Failed to attach/refresh!");
+    /// let config = process.inject_dll_with_data(r#"C:\temp\native.dll"#, &[1, 0, 0, 0]).expect("Failed to inject!");
+    /// ```
+    pub fn inject_dll_with_data(&self, dll_path: &str, data: &[u8]) -> Result<usize, String>
+    {
+        if !self.is_attached()
+        {
+            return Err(String::from("Process not attached"));
+        }
+
+        self.inject_dll(dll_path)?;
+
+        unsafe
+        {
+            let process_handle = self.process_data.borrow().handle.raw();
+            let allocated_data = VirtualAllocEx(process_handle, None, data.len().max(1), MEM_COMMIT | MEM_RESERVE, PAGE_READWRITE);
+            if allocated_data.is_null()
+            {
+                return Err(String::from("Failed to allocate memory for data"));
+            }
+
+            if !self.write_memory_abs(allocated_data as usize, data)
+            {
+                let _ = VirtualFreeEx(process_handle, allocated_data, 0, MEM_RELEASE);
+                return Err(String::from("Failed to write data"));
+            }
+            return Ok(allocated_data as usize);
+        }
+    }
 }
\ No newline at end of file
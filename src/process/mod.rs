@@ -14,21 +14,46 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use std::cell::RefCell;
+use std::cell::{Ref, RefCell};
+use std::collections::HashSet;
 use std::rc::Rc;
+use std::time::Duration;
 
-use windows::Win32::Foundation::HANDLE;
+use windows::Win32::Foundation::{CloseHandle, HINSTANCE, MAX_PATH};
+use windows::Win32::System::ProcessStatus::K32GetModuleFileNameExW;
+use windows::Win32::System::Threading::{GetCurrentProcessId, GetExitCodeProcess, OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE};
 
-use crate::process_data::ProcessData;
+use crate::helpers::{get_file_name_from_string, normalize_process_name, w32str_to_string};
+use crate::process_data::{DEFAULT_ACCESS_RIGHTS, DEFAULT_REFRESH_INTERVAL, ProcessData};
+use crate::process_handle::ProcessHandle;
 use crate::process_module::ProcessModule;
+mod access;
+mod builder;
+mod capture;
+mod command_line;
+mod diagnostics;
+#[cfg(target_arch = "x86_64")]
+mod hardware_breakpoint;
 mod inject_dll;
+mod patch;
 mod scanning;
 mod read_write;
 mod refresh;
 mod process_modules;
 mod process_name;
+mod query_address;
+mod thread_context;
+mod terminate;
+mod threads;
+mod window_handles;
 
-const STILL_ACTIVE: u32 = 259;
+#[cfg(target_arch = "x86_64")]
+pub use hardware_breakpoint::{BpKind, BpSize};
+pub use builder::ProcessBuilder;
+pub use process_modules::ModuleChanges;
+pub use refresh::RefreshStatus;
+
+pub(crate) const STILL_ACTIVE: u32 = 259;
 
 /// Wraps a native process and allows memory access/manipulation
 ///
@@ -40,7 +65,7 @@ const STILL_ACTIVE: u32 = 259;
 /// let mut process = Process::new("name_of_process.exe");
 /// if process.refresh().is_ok()
 /// {
-///     process.write_memory_abs(0x1234, &u32::to_ne_bytes(10));
+///     process.write_memory_abs(0x1234, &u32::to_le_bytes(10));
 ///     let result = process.read_u32_rel(Some(0x1234));
 ///     println!("Result: {}", result);
 /// }
@@ -53,6 +78,7 @@ pub struct Process
 impl Process
 {
     /// Creates a new process based on the process name.
+    /// Directory components are stripped from the name and .exe is appended when the name has no extension.
     ///
     /// # Examples
     ///
@@ -67,18 +93,149 @@ impl Process
         {
             process_data: Rc::new(RefCell::new(ProcessData
             {
-                name: String::from(name),
+                name: normalize_process_name(name),
                 attached: false,
                 id: 0,
-                handle: HANDLE::default(),
+                handle: ProcessHandle::default(),
                 filename: String::new(),
                 path: String::new(),
                 main_module: ProcessModule::default(),
                 modules: Vec::new(),
+                applied_patches: HashSet::new(),
+                from_buffer: false,
+                buffer_regions: Vec::new(),
+                last_pid: 0,
+                dry_run: false,
+                is_64_bit: true,
+                read_only: false,
+                refresh_interval: DEFAULT_REFRESH_INTERVAL,
+                last_enumeration: None,
+                access_rights: DEFAULT_ACCESS_RIGHTS,
+                dump_main_module: true,
+                match_full_path: false,
+                redump_on_refresh: false,
+                target_pid: None,
+                window_title: None,
+                match_window_substring: false,
             }))
         }
     }
 
+    /// Creates a process that reads from and writes to a buffer instead of a running process. The buffer acts as the main module,
+    /// located at the given base address. Scans, pointers and reads all work against the buffer, which allows testing offset
+    /// and scanning logic without a running process. The process is attached from the start and refreshing does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut memory = vec![0u8; 0x100];
+    /// memory[0x10..0x14].copy_from_slice(&1234u32.to_le_bytes());
+    ///
+    /// let process = Process::new_from_buffer(memory, 0x140000000);
+    /// let pointer = process.create_pointer(0x140000000, vec![]);
+    /// assert_eq!(pointer.read_u32_rel(Some(0x10)), 1234);
+    /// ```
+    pub fn new_from_buffer(memory: Vec<u8>, base_address: usize) -> Self
+    {
+        let size = memory.len();
+        let mut main_module = ProcessModule::new(0, String::new(), String::new(), base_address, size);
+        main_module.memory = memory;
+
+        Process
+        {
+            process_data: Rc::new(RefCell::new(ProcessData
+            {
+                attached: true,
+                main_module,
+                from_buffer: true,
+                ..ProcessData::default()
+            }))
+        }
+    }
+
+    /// Adds a region of memory at the given address to a process created with new_from_buffer, for memory outside of the main module
+    /// such as the heap. Reads and writes that fall entirely within the region use it. Does nothing for a live process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new_from_buffer(vec![0u8; 0x100], 0x140000000);
+    /// process.add_buffer_region(0x20000000, vec![0u8; 0x1000]);
+    /// ```
+    pub fn add_buffer_region(&mut self, address: usize, memory: Vec<u8>)
+    {
+        let mut process_data = self.process_data.borrow_mut();
+        if process_data.from_buffer
+        {
+            process_data.buffer_regions.push((address, memory));
+        }
+    }
+
+    /// Attaches to the current process, for use from a dll that was injected into the target.
+    /// Fills in the handle, id, modules and bitness of the current process directly, without enumerating running processes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let process = Process::attach_self()?;
+    /// let pointer = process.scan_rel("GameDataMan", "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0])?;
+    /// ```
+    pub fn attach_self() -> Result<Process, String>
+    {
+        unsafe
+        {
+            let id = GetCurrentProcessId();
+            let handle = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ | PROCESS_VM_WRITE | PROCESS_VM_OPERATION, false, id);
+            if handle.is_err()
+            {
+                return Err(String::from("Failed to open current process"));
+            }
+            let handle = handle.unwrap();
+
+            let mut mod_name = [0; MAX_PATH as usize];
+            if K32GetModuleFileNameExW(handle, HINSTANCE(0), &mut mod_name) == 0
+            {
+                let _ = CloseHandle(handle);
+                return Err(String::from("Failed to get current process path"));
+            }
+            let path = w32str_to_string(&mod_name.to_vec());
+            let filename = get_file_name_from_string(&path);
+
+            let mut modules = Process::get_process_modules(handle);
+            if modules.is_empty()
+            {
+                let _ = CloseHandle(handle);
+                return Err(String::from("Failed to get process modules"));
+            }
+            let mut main_module = modules.remove(0);
+            main_module.dump_memory(handle);
+
+            return Ok(Process
+            {
+                process_data: Rc::new(RefCell::new(ProcessData
+                {
+                    attached: true,
+                    name: filename.clone(),
+                    filename,
+                    path,
+                    id,
+                    handle: ProcessHandle::new(handle),
+                    main_module,
+                    modules,
+                    last_pid: id,
+                    is_64_bit: cfg!(target_pointer_width = "64"),
+                    ..ProcessData::default()
+                }))
+            });
+        }
+    }
+
     /// Returns if the process is "attached" and can be read/written from/to
     ///
     /// # Examples
@@ -97,5 +254,325 @@ impl Process
     /// let attached = process.is_attached();
     /// ```
     pub fn is_attached(&self) -> bool {return self.process_data.borrow().attached;}
+
+    /// Returns a duplicate of the handle of the attached process, or None when not attached. The duplicate is owned by the caller,
+    /// so it stays open when the process detaches or re-attaches, and is closed when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(handle) = process.get_handle()
+    /// {
+    ///     println!("{}", handle.is_valid());
+    /// }
+    /// ```
+    pub fn get_handle(&self) -> Option<ProcessHandle>
+    {
+        return self.process_data.borrow().handle.try_clone();
+    }
+
+    /// Returns if the process was attached read-only, because it could not be opened with write access. Writes fail on a read-only attachment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if process.is_read_only()
+    /// {
+    ///     println!("attached read-only");
+    /// }
+    /// ```
+    pub fn is_read_only(&self) -> bool
+    {
+        return self.process_data.borrow().read_only;
+    }
+
+    /// Sets the minimum time between searches for the process in refresh while not attached. Refreshing again within the interval
+    /// returns an error without enumerating running processes, which keeps polling refresh every frame cheap. Defaults to 250 milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.set_refresh_interval(Duration::from_secs(1));
+    /// ```
+    pub fn set_refresh_interval(&mut self, interval: Duration)
+    {
+        self.process_data.borrow_mut().refresh_interval = interval;
+    }
+
+    /// Enables or disables dry run mode. In dry run mode, writes through this process and its pointers are logged
+    /// with the log crate at info level instead of being performed, and report success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.set_dry_run(true);
+    /// process.write_u32_rel(Some(0x1234), 100);
+    /// ```
+    pub fn set_dry_run(&mut self, enabled: bool)
+    {
+        self.process_data.borrow_mut().dry_run = enabled;
+    }
+
+    /// Enables or disables re-dumping the main module on every refresh while attached, see redump_main_module.
+    /// Keeps scans up to date with self-modifying or hot-patched code, at the cost of reading the whole module each refresh.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.set_redump_on_refresh(true);
+    /// process.refresh()?;
+    /// ```
+    pub fn set_redump_on_refresh(&mut self, enabled: bool)
+    {
+        self.process_data.borrow_mut().redump_on_refresh = enabled;
+    }
+
+    /// Returns a copy of the main module of the attached process, including its memory dump.
+    /// Panics if the process is not attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let main_module = process.get_main_module();
+    /// ```
+    pub fn get_main_module(&self) -> ProcessModule
+    {
+        if !self.is_attached()
+        {
+            panic!("get_main_module called on a process that is not attached");
+        }
+        return self.process_data.borrow().main_module.clone();
+    }
+
+    /// Borrows the main module of the attached process, without copying its memory dump.
+    /// Returns None if the process is not attached. The process can not be refreshed while the borrow is held.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(main_module) = process.try_get_main_module()
+    /// {
+    ///     println!("{:#x}", main_module.base_address);
+    /// }
+    /// ```
+    pub fn try_get_main_module(&self) -> Option<Ref<'_, ProcessModule>>
+    {
+        if !self.is_attached()
+        {
+            return None;
+        }
+        return Some(Ref::map(self.process_data.borrow(), |process_data| &process_data.main_module));
+    }
+
+    /// Returns if the attached process is still running, without refreshing or changing the attached state.
+    /// Returns false when not attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh().unwrap();
+    ///
+    /// //returns false once name_of_process.exe has exited
+    /// let running = process.is_running();
+    /// ```
+    pub fn is_running(&self) -> bool
+    {
+        let process_data = self.process_data.borrow();
+        if !process_data.attached
+        {
+            return false;
+        }
+
+        if process_data.from_buffer
+        {
+            return true;
+        }
+
+        let mut lp_exit_code: u32 = 0;
+        unsafe
+        {
+            return GetExitCodeProcess(process_data.handle.raw(), &mut lp_exit_code).is_ok() && lp_exit_code == STILL_ACTIVE;
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests
+{
+    use crate::prelude::*;
+
+    const BASE: usize = 0x140000000;
+
+    #[test]
+    fn pointer_resolves_against_buffer()
+    {
+        let mut memory = vec![0u8; 0x100];
+        memory[0x10..0x14].copy_from_slice(&1234u32.to_le_bytes());
+        //A pointer at 0x20 to the structure at 0x40, which holds a value at 0x8
+        memory[0x20..0x28].copy_from_slice(&((BASE + 0x40) as u64).to_le_bytes());
+        memory[0x48..0x4c].copy_from_slice(&5678u32.to_le_bytes());
+
+        let process = Process::new_from_buffer(memory, BASE);
+        assert_eq!(process.create_pointer(BASE, vec![]).read_u32_rel(Some(0x10)), 1234);
+        assert_eq!(process.create_pointer(BASE, vec![0x20]).read_u32_rel(Some(0x8)), 5678);
+        assert_eq!(process.create_pointer(BASE + 0x20, vec![0, 0x8]).read_u32_rel(None), 5678);
+    }
+
+    #[test]
+    fn writes_fail_instead_of_panicking_while_the_main_module_is_borrowed()
+    {
+        let process = Process::new_from_buffer(vec![0u8; 0x100], BASE);
+        let pointer = process.create_pointer(BASE, vec![]);
+        let main_module = process.try_get_main_module().expect("buffer process is attached");
+
+        assert!(!process.write_memory_rel(Some(0x10), &[1, 2, 3, 4]));
+        assert!(!pointer.write_memory_rel(Some(0x10), &[1, 2, 3, 4]));
+        assert!(!process.read_memory_abs(0x1234, &mut [0u8; 4]));
+        assert_eq!(main_module.memory[0x10], 0);
+    }
+
+    #[test]
+    fn make_signature_without_a_length_limit()
+    {
+        let process = Process::new_from_buffer(vec![0x48, 0x8B, 0x05, 0x90], BASE);
+        assert_eq!(process.make_signature(BASE + 1, usize::MAX), process.make_signature(BASE + 1, 3));
+        assert!(process.make_signature(BASE + 1, usize::MAX).is_some());
+    }
+
+    #[test]
+    fn pointer_list_rejects_a_garbage_count()
+    {
+        let mut memory = vec![0u8; 0x100];
+        //A count of 2 at 0x8 and the array at 0x40
+        memory[0x08..0x0c].copy_from_slice(&2u32.to_le_bytes());
+        memory[0x10..0x18].copy_from_slice(&((BASE + 0x40) as u64).to_le_bytes());
+        memory[0x40..0x48].copy_from_slice(&((BASE + 0x80) as u64).to_le_bytes());
+        memory[0x48..0x50].copy_from_slice(&((BASE + 0x90) as u64).to_le_bytes());
+        memory[0x84..0x88].copy_from_slice(&100u32.to_le_bytes());
+        memory[0x94..0x98].copy_from_slice(&200u32.to_le_bytes());
+
+        let process = Process::new_from_buffer(memory, BASE);
+        let list = process.create_pointer(BASE, vec![]);
+        let health: Vec<u32> = list.read_pointer_list_rel(0x8, 0x10, 0x8, 16).iter().map(|entity| entity.read_u32_rel(Some(0x4))).collect();
+        assert_eq!(health, vec![100, 200]);
+
+        process.write_u32_rel(Some(0x8), 0xFFFFFFFF);
+        assert!(list.read_pointer_list_rel(0x8, 0x10, 0x8, 16).is_empty());
+    }
+
+    #[test]
+    fn tarray_rejects_a_garbage_header()
+    {
+        let mut memory = vec![0u8; 0x100];
+        //A TArray at 0x10 with 3 of 4 u32 elements at 0x40
+        memory[0x10..0x18].copy_from_slice(&((BASE + 0x40) as u64).to_le_bytes());
+        memory[0x18..0x1c].copy_from_slice(&3i32.to_le_bytes());
+        memory[0x1c..0x20].copy_from_slice(&4i32.to_le_bytes());
+        for (i, value) in [10u32, 20, 30].iter().enumerate()
+        {
+            memory[0x40 + i * 4..0x44 + i * 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        let process = Process::new_from_buffer(memory, BASE);
+        let pointer = process.create_pointer(BASE, vec![]);
+        assert_eq!(pointer.read_tarray_rel::<u32>(Some(0x10), 16), vec![10, 20, 30]);
+        assert!(pointer.read_tarray_rel::<u32>(Some(0x10), 2).is_empty());
+
+        //A torn header with a huge count is rejected before allocating
+        process.write_i32_rel(Some(0x18), i32::MAX);
+        process.write_i32_rel(Some(0x1c), i32::MAX);
+        assert!(pointer.read_tarray_rel::<u32>(Some(0x10), 16).is_empty());
+    }
+
+    #[test]
+    fn scan_iter_finds_every_match()
+    {
+        let process = Process::new_from_buffer(vec![0xAA, 0xAA, 0xAA, 0x00, 0xAA, 0xAA], BASE);
+        let matches: Vec<usize> = process.scan_iter("AA AA").collect();
+        assert_eq!(matches, vec![BASE, BASE + 1, BASE + 4]);
+        assert_eq!(process.scan_iter("AA ? AA").count(), 2);
+        assert_eq!(process.scan_iter("BB").next(), None);
+    }
+
+    #[test]
+    fn null_pointer_does_not_resolve()
+    {
+        let process = Process::new_from_buffer(vec![0u8; 0x100], BASE);
+        let pointer = process.create_pointer(BASE, vec![0, 0x10]);
+        assert!(pointer.resolve_path().is_none());
+    }
+
+    #[test]
+    fn scan_abs_finds_pattern_in_buffer()
+    {
+        let mut memory = vec![0u8; 0x100];
+        memory[0x80..0x86].copy_from_slice(&[0x56, 0x8b, 0xf1, 0xa1, 0x44, 0x33]);
+
+        let process = Process::new_from_buffer(memory, BASE);
+        let pointer = process.scan_abs("test", "56 8b f1 a1 ? ?", 4, vec![]).unwrap();
+        assert_eq!(pointer.get_base_address(), BASE + 0x84);
+        assert!(process.scan_abs("test", "56 8b f1 a2", 0, vec![]).is_err());
+    }
+
+    #[test]
+    fn scan_rel_resolves_forward_and_backward_displacements()
+    {
+        let mut memory = vec![0u8; 0x100];
+        //mov rax, [rip + 0x10] at 0x40 and mov rax, [rip - 0x30] at 0x60
+        memory[0x40..0x47].copy_from_slice(&[0x48, 0x8b, 0x05, 0x10, 0x00, 0x00, 0x00]);
+        memory[0x60..0x67].copy_from_slice(&[0x48, 0x8b, 0x0d, 0xd0, 0xff, 0xff, 0xff]);
+
+        let process = Process::new_from_buffer(memory, BASE);
+        let forward = process.scan_rel("forward", "48 8b 05 ? ? ? ?", 3, 7, vec![]).unwrap();
+        assert_eq!(forward.get_base_address(), BASE + 0x47 + 0x10);
+        let backward = process.scan_rel("backward", "48 8b 0d ? ? ? ?", 3, 7, vec![]).unwrap();
+        assert_eq!(backward.get_base_address(), BASE + 0x67 - 0x30);
+    }
+
+    #[test]
+    fn vfunc_resolves_with_32_bit_pointers()
+    {
+        let base = 0x400000;
+        let mut memory = vec![0u8; 0x100];
+        //Object pointer at 0x10, the object at 0x40 starts with its vtable pointer, the vtable is at 0x80
+        memory[0x10..0x14].copy_from_slice(&((base + 0x40) as u32).to_le_bytes());
+        memory[0x40..0x44].copy_from_slice(&((base + 0x80) as u32).to_le_bytes());
+        memory[0x88..0x8c].copy_from_slice(&0x401234u32.to_le_bytes());
+
+        let process = Process::new_from_buffer(memory, base);
+        process.process_data.borrow_mut().is_64_bit = false;
+
+        let pointer = process.create_pointer(base, vec![]);
+        assert_eq!(pointer.read_vfunc_rel(0x10, 2), Some(0x401234));
+    }
+}
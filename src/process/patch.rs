@@ -0,0 +1,85 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::ffi::c_void;
+use windows::Win32::System::Diagnostics::Debug::FlushInstructionCache;
+use crate::prelude::*;
+
+impl Process
+{
+    /// Writes a patch to an absolute address, but only once per attachment. The key identifies the patch,
+    /// returns Ok(false) without writing when a patch with the same key was already applied.
+    /// Applied keys are reset when the process detaches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let applied = process.patch_once("nop_damage", 0x1234, &[0x90, 0x90, 0x90])?;
+    /// ```
+    pub fn patch_once(&self, key: &str, address: usize, bytes: &[u8]) -> Result<bool, String>
+    {
+        if !self.is_attached()
+        {
+            return Err(String::from("Process not attached"));
+        }
+
+        if self.is_read_only()
+        {
+            return Err(String::from("Read-only attachment"));
+        }
+
+        if self.process_data.borrow().applied_patches.contains(key)
+        {
+            return Ok(false);
+        }
+
+        if !self.write_memory_abs(address, bytes)
+        {
+            return Err(format!("Failed to apply patch: {}", key));
+        }
+        self.flush_instruction_cache(address, bytes.len());
+
+        self.process_data.borrow_mut().applied_patches.insert(String::from(key));
+        return Ok(true);
+    }
+
+    /// Flushes the instruction cache of the attached process for a range of memory, so that patched code is not executed from stale cache.
+    /// Call this after writing code, patch_once does so automatically. Returns if the flush succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.write_memory_abs(0x1234, &[0x90, 0x90, 0x90]);
+    /// process.flush_instruction_cache(0x1234, 3);
+    /// ```
+    pub fn flush_instruction_cache(&self, address: usize, size: usize) -> bool
+    {
+        let process_data = self.process_data.borrow();
+        if !process_data.attached || process_data.from_buffer
+        {
+            return false;
+        }
+        return unsafe { FlushInstructionCache(process_data.handle.raw(), Some(address as *const c_void), size).is_ok() };
+    }
+}
@@ -17,14 +17,91 @@
 use std::ffi::c_void;
 use std::mem::size_of;
 use windows::Win32::Foundation::{HANDLE, HINSTANCE, HMODULE, MAX_PATH};
-use windows::Win32::System::ProcessStatus::{K32EnumProcessModules, K32GetModuleFileNameExW, K32GetModuleInformation, MODULEINFO};
+use windows::Win32::System::ProcessStatus::{ENUM_PROCESS_MODULES_EX_FLAGS, K32EnumProcessModulesEx, K32GetModuleFileNameExW, K32GetModuleInformation, LIST_MODULES_32BIT, LIST_MODULES_DEFAULT, MODULEINFO};
 use crate::helpers::{get_file_name_from_string, w32str_to_string};
 use crate::process::Process;
 use crate::process_module::ProcessModule;
 
+/// The modules that were loaded and unloaded since the last time modules were enumerated, see Process::poll_module_changes
+#[derive(Clone, Default)]
+pub struct ModuleChanges
+{
+    pub loaded: Vec<ProcessModule>,
+    pub unloaded: Vec<ProcessModule>,
+}
+
 impl Process
 {
+    /// Enumerates the modules of the attached process and compares them to the cached modules, returning the modules that were
+    /// loaded and unloaded since the last enumeration. Updates the cached modules. Returns no changes when not attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let changes = process.poll_module_changes();
+    /// for module in changes.loaded
+    /// {
+    ///     println!("loaded {} at {:#x}", module.name, module.base_address);
+    /// }
+    /// ```
+    pub fn poll_module_changes(&mut self) -> ModuleChanges
+    {
+        let mut changes = ModuleChanges::default();
+        if !self.is_attached() || self.process_data.borrow().from_buffer
+        {
+            return changes;
+        }
+
+        let mut process_data = self.process_data.borrow_mut();
+        let mut modules = Process::get_process_modules(process_data.handle.raw());
+        if modules.is_empty()
+        {
+            return changes;
+        }
+
+        //The first module is the main module, which is not part of the cached modules
+        modules.remove(0);
+
+        let same = |a: &ProcessModule, b: &ProcessModule| a.base_address == b.base_address && a.path == b.path;
+        changes.loaded = modules.iter().filter(|module| !process_data.modules.iter().any(|cached| same(cached, module))).cloned().collect();
+        changes.unloaded = process_data.modules.iter().filter(|cached| !modules.iter().any(|module| same(module, cached))).cloned().collect();
+
+        process_data.modules = modules;
+        return changes;
+    }
+
+    /// Returns the 32 bit modules of the attached process. When a 64 bit process is attached to a 32 bit (WOW64) process,
+    /// the regular module enumeration only returns the 64 bit modules, missing the DLLs the target actually uses.
+    /// Returns an empty list when not attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let modules = process.get_modules_wow64();
+    /// ```
+    pub fn get_modules_wow64(&self) -> Vec<ProcessModule>
+    {
+        if !self.is_attached()
+        {
+            return Vec::new();
+        }
+        return Process::get_process_modules_filtered(self.process_data.borrow().handle.raw(), LIST_MODULES_32BIT);
+    }
+
     pub(crate) fn get_process_modules(process_handle: HANDLE) -> Vec<ProcessModule>
+    {
+        return Process::get_process_modules_filtered(process_handle, LIST_MODULES_DEFAULT);
+    }
+
+    fn get_process_modules_filtered(process_handle: HANDLE, filter: ENUM_PROCESS_MODULES_EX_FLAGS) -> Vec<ProcessModule>
     {
         unsafe
             {
@@ -32,12 +109,15 @@ impl Process
 
                 //Get amount of hmodules in current process
                 let mut required_size: u32 = 0;
-                let _ = K32EnumProcessModules(process_handle, 0 as *mut HMODULE, 0, &mut required_size);
+                let _ = K32EnumProcessModulesEx(process_handle, 0 as *mut HMODULE, 0, &mut required_size, filter.0);
                 let size = (required_size / size_of::<HINSTANCE>() as u32) as u32;
 
                 //Get modules
                 let mut modules: Vec<HMODULE> = vec![HMODULE(0); size as usize];
-                let _ = K32EnumProcessModules(process_handle, modules.as_mut_ptr(), required_size.clone(), &mut required_size).unwrap();
+                if !K32EnumProcessModulesEx(process_handle, modules.as_mut_ptr(), required_size.clone(), &mut required_size, filter.0).as_bool()
+                {
+                    return result;
+                }
 
                 for i in 0..modules.len()
                 {
@@ -0,0 +1,87 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::ffi::c_void;
+use std::mem::size_of;
+use windows::Win32::System::Memory::{MEMORY_BASIC_INFORMATION, VirtualQueryEx};
+use crate::memory_basic_information::MemoryBasicInformation;
+use crate::prelude::Process;
+
+impl Process
+{
+    /// Returns information about the region of pages that contains the given address, such as the base, size, state and protection.
+    /// Returns None when the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(info) = process.query_address(0x140001000)
+    /// {
+    ///     println!("{:#x} {:#x} {:?}", info.base_address, info.region_size, info.protect);
+    /// }
+    /// ```
+    pub fn query_address(&self, address: usize) -> Option<MemoryBasicInformation>
+    {
+        unsafe
+        {
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+            if VirtualQueryEx(self.process_data.borrow().handle.raw(), Some(address as *const c_void), &mut info, size_of::<MEMORY_BASIC_INFORMATION>()) == 0
+            {
+                return None;
+            }
+            return Some(MemoryBasicInformation::from(info));
+        }
+    }
+
+    /// Returns all memory regions of the attached process, from the lowest to the highest address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// for region in process.get_memory_regions()
+    /// {
+    ///     println!("{:#x} {:#x} {:?}", region.base_address, region.region_size, region.protect);
+    /// }
+    /// ```
+    pub fn get_memory_regions(&self) -> Vec<MemoryBasicInformation>
+    {
+        let mut result = Vec::new();
+        let mut address = 0usize;
+        while let Some(info) = self.query_address(address)
+        {
+            if info.region_size == 0
+            {
+                break;
+            }
+
+            result.push(info);
+            match info.base_address.checked_add(info.region_size)
+            {
+                Some(next) => address = next,
+                None => break,
+            }
+        }
+        return result;
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::prelude::{BaseReadWrite, Process, ReadWrite};
+use crate::prelude::{BaseReadWrite, Pod, Process, ReadWrite};
 
 impl BaseReadWrite for Process
 {
@@ -25,7 +25,7 @@ impl BaseReadWrite for Process
         {
             address += offset.unwrap();
         }
-        return self.read_with_handle(self.process_data.borrow().handle, address, buffer);
+        return self.read_checked(address, buffer);
     }
 
     fn write_memory_rel(&self, offset: Option<usize>, buffer: &[u8]) -> bool
@@ -35,17 +35,128 @@ impl BaseReadWrite for Process
         {
             address += offset.unwrap();
         }
-        return self.write_with_handle(self.process_data.borrow().handle, address, buffer);
+        return self.write_memory_abs(address, buffer);
     }
 
     fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
     {
-        return self.read_with_handle(self.process_data.borrow().handle, address, buffer);
+        return self.read_checked(address, buffer);
     }
 
     fn write_memory_abs(&self, address: usize, buffer: &[u8]) -> bool
     {
-        return self.write_with_handle(self.process_data.borrow().handle, address, buffer);
+        if self.process_data.borrow().dry_run_write(address, buffer)
+        {
+            return true;
+        }
+        if self.process_data.borrow().reject_read_only_write(address)
+        {
+            return false;
+        }
+        if self.process_data.borrow().from_buffer
+        {
+            //Fails instead of panicking while the process data is borrowed, for example through try_get_main_module
+            return self.process_data.try_borrow_mut().is_ok_and(|mut process_data| process_data.write_buffer(address, buffer));
+        }
+        return self.write_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
+    }
+}
+
+impl Process
+{
+    /// Write a value of any Pod type to an absolute address. The bytes of the value are copied as-is,
+    /// see Pod for the requirements on T. Returns if the write succeeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let success = process.write_value_abs(0x1234, 10.0f32);
+    /// ```
+    pub fn write_value_abs<T: Pod>(&self, address: usize, value: T) -> bool
+    {
+        let buffer = unsafe { std::slice::from_raw_parts(&value as *const T as *const u8, std::mem::size_of::<T>()) };
+        return self.write_memory_abs(address, buffer);
+    }
+
+    /// Relatively read a pointer from an optional offset, reading 8 bytes from a 64 bit process and 4 bytes from a 32 bit process.
+    /// Returns 0 when the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.read_ptr_rel(Some(0x1234));
+    /// ```
+    pub fn read_ptr_rel(&self, offset: Option<usize>) -> usize
+    {
+        if self.process_data.borrow().is_64_bit
+        {
+            let mut buffer = [0; 8];
+            if !self.read_memory_rel(offset, &mut buffer)
+            {
+                return 0;
+            }
+            return u64::from_le_bytes(buffer) as usize;
+        }
+
+        let mut buffer = [0; 4];
+        if !self.read_memory_rel(offset, &mut buffer)
+        {
+            return 0;
+        }
+        return u32::from_le_bytes(buffer) as usize;
+    }
+
+    /// Read a u32 through several candidate pointer paths, given as base address and offsets, and return the first successful read.
+    /// Candidates that encounter a null pointer or fail to read are skipped. Useful when a value moves between game versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let health = process.read_u32_any(&[(0x141c04a08, vec![0x68, 0x3e8]), (0x141c05a08, vec![0x70, 0x3f0])]);
+    /// ```
+    pub fn read_u32_any(&self, candidates: &[(usize, Vec<usize>)]) -> Option<u32>
+    {
+        for (base_address, offsets) in candidates
+        {
+            if let Some(value) = self.create_pointer_slice(*base_address, offsets).read_u32_with_offsets(&[])
+            {
+                return Some(value);
+            }
+        }
+        return None;
+    }
+
+    /// Read from the attached process. When the read fails because the process exited, the process is detached.
+    fn read_checked(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        if self.process_data.borrow().from_buffer
+        {
+            return self.process_data.borrow().read_buffer(address, buffer);
+        }
+
+        let handle = self.process_data.borrow().handle.raw();
+        if self.read_with_handle(handle, address, buffer)
+        {
+            return true;
+        }
+        //Skipped while the process data is borrowed, refresh detaches it later
+        if let Ok(mut process_data) = self.process_data.try_borrow_mut()
+        {
+            process_data.detach_if_exited();
+        }
+        return false;
     }
 }
 
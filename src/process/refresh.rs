@@ -15,19 +15,38 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use std::mem::size_of;
+use std::time::Instant;
 use windows::Win32::Foundation::{BOOL, CloseHandle, HANDLE, HINSTANCE};
 use windows::Win32::System::ProcessStatus::{K32EnumProcesses, K32GetModuleFileNameExW};
-use windows::Win32::System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE};
+use windows::Win32::System::Threading::{GetExitCodeProcess, IsWow64Process, OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
 use crate::helpers::{get_file_name_from_string, w32str_to_string};
 use crate::prelude::Process;
 use crate::process::STILL_ACTIVE;
-use crate::process_module::ProcessModule;
+use crate::process_handle::ProcessHandle;
+
+/// Describes the outcome of a successful refresh
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefreshStatus
+{
+    /// The process was already attached and is still running
+    StillAttached,
+    /// Attached to the process for the first time
+    Attached,
+    /// Attached to a different instance of the process than before, for example after the game restarted
+    Reattached,
+}
 
 impl Process
 {
     /// Attempts to "attach" to a running process by name.
     /// Returns an error when the process is not running or when it has exited.
     /// Caches the main module so that pattern scans can be done against it.
+    /// While not attached, the search for the process is throttled to once per refresh interval, see set_refresh_interval.
+    /// When the process can't be opened with write access, attaches read-only and writes fail, see is_read_only.
+    /// Returns Reattached when a new instance was attached to after a previous one exited, so that consumers know to rescan and reset their state.
+    /// While attached, re-dumps the main module when enabled with set_redump_on_refresh.
+    /// A process built with ProcessBuilder::pid or ProcessBuilder::window_title is attached to through that pid or window instead of by name.
+    /// The pid is only used for the first attach, after that process exits refresh searches by name.
     ///
     /// # Examples
     ///
@@ -35,32 +54,64 @@ impl Process
     /// use mem_rs::prelude::*;
     ///
     /// let mut process = Process::new("name_of_process.exe");
-    /// process.refresh().expect("Failed to attach/refresh!");
+    /// if process.refresh().expect("Failed to attach/refresh!") == RefreshStatus::Reattached
+    /// {
+    ///     println!("Process restarted");
+    /// }
     /// ```
-    pub fn refresh(&mut self) -> Result<(), String>
+    pub fn refresh(&mut self) -> Result<RefreshStatus, String>
     {
+        if self.process_data.borrow().from_buffer
+        {
+            return Ok(RefreshStatus::StillAttached);
+        }
+
         unsafe
         {
             //Check if a previously attached process has exited
             let mut lp_exit_code: u32 = 0;
-            if self.process_data.borrow().attached && (!GetExitCodeProcess(self.process_data.borrow().handle, &mut lp_exit_code).is_ok() || lp_exit_code != STILL_ACTIVE)
+            if self.process_data.borrow().attached && (!GetExitCodeProcess(self.process_data.borrow().handle.raw(), &mut lp_exit_code).is_ok() || lp_exit_code != STILL_ACTIVE)
             {
-                let mut process_data = self.process_data.borrow_mut();
-
-                process_data.attached = false;
-                process_data.id = 0;
-                process_data.handle = HANDLE::default();
-                process_data.filename = String::new();
-                process_data.path = String::new();
-                process_data.main_module = ProcessModule::default();
-                process_data.modules = Vec::new();
-
+                self.process_data.borrow_mut().detach();
                 return Err(String::from("Process exited"));
             }
 
             if self.process_data.borrow().attached
             {
-                return Ok(());
+                if self.process_data.borrow().redump_on_refresh
+                {
+                    self.redump_main_module();
+                }
+                return Ok(RefreshStatus::StillAttached);
+            }
+
+            //Throttle enumerating running processes while waiting for the process to start
+            {
+                let mut process_data = self.process_data.borrow_mut();
+                if process_data.last_enumeration.is_some_and(|last| last.elapsed() < process_data.refresh_interval)
+                {
+                    return Err(String::from("waiting"));
+                }
+                process_data.last_enumeration = Some(Instant::now());
+            }
+
+            //Attach through the pid or window title configured with ProcessBuilder instead of searching by name
+            let target_pid = self.process_data.borrow().target_pid;
+            if let Some(pid) = target_pid
+            {
+                //Only attach to the pid once, windows reuses the pid of an exited process for unrelated processes
+                let result = self.attach_pid(pid);
+                if result.is_ok()
+                {
+                    self.process_data.borrow_mut().target_pid = None;
+                }
+                return result;
+            }
+            let window_title = self.process_data.borrow().window_title.clone();
+            if let Some(title) = window_title
+            {
+                let match_substring = self.process_data.borrow().match_window_substring;
+                return self.attach_by_window_title(&title, match_substring);
             }
 
             //Look for a running process with the correct name and attach to it
@@ -77,14 +128,16 @@ impl Process
             {
                 let pid = process_ids[i];
 
-                match OpenProcess(
-                    PROCESS_QUERY_INFORMATION
-                        | PROCESS_VM_READ
-                        | PROCESS_VM_WRITE
-                        | PROCESS_VM_OPERATION,
-                    BOOL(0),
-                    pid,
-                )
+                //Fall back to a read-only attachment when the process can't be opened with write access, such as for protected processes
+                let mut read_only = false;
+                let access_rights = self.process_data.borrow().access_rights;
+                let handle_result = OpenProcess(access_rights, BOOL(0), pid).or_else(|_|
+                {
+                    read_only = true;
+                    OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, BOOL(0), pid)
+                });
+
+                match handle_result
                 {
                     Ok(handle) =>
                     {
@@ -97,22 +150,10 @@ impl Process
 
                             //println!("{}", filename);
 
-                            if self.process_data.borrow().name.to_lowercase() == file_name.to_lowercase()
+                            let candidate = if self.process_data.borrow().match_full_path { &file_path } else { &file_name };
+                            if self.process_data.borrow().name.to_lowercase() == candidate.to_lowercase()
                             {
-                                let mut modules = Process::get_process_modules(handle);
-
-                                let mut process_data = self.process_data.borrow_mut();
-
-                                process_data.id = pid;
-                                process_data.handle = handle;
-                                process_data.filename = file_name;
-                                process_data.path = file_path;
-                                process_data.attached = true;
-                                process_data.main_module = modules.remove(0);
-                                process_data.main_module.dump_memory(handle);
-                                process_data.modules = modules;
-
-                                return Ok(());
+                                return self.attach_handle(pid, handle, read_only, file_path, file_name);
                             }
                         }
 
@@ -124,4 +165,187 @@ impl Process
             return Err(String::from("Process not running"));
         }
     }
-}
\ No newline at end of file
+
+    /// Cheaper variant of refresh for tight polling loops. When attached, only checks if the process is still running
+    /// with a single GetExitCodeProcess call. When not attached, behaves the same as refresh.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh_fast().expect("Failed to attach/refresh!");
+    /// ```
+    pub fn refresh_fast(&mut self) -> Result<RefreshStatus, String>
+    {
+        {
+            let mut process_data = self.process_data.borrow_mut();
+            if process_data.from_buffer
+            {
+                return Ok(RefreshStatus::StillAttached);
+            }
+
+            if process_data.attached
+            {
+                let mut lp_exit_code: u32 = 0;
+                if unsafe { GetExitCodeProcess(process_data.handle.raw(), &mut lp_exit_code).is_ok() } && lp_exit_code == STILL_ACTIVE
+                {
+                    return Ok(RefreshStatus::StillAttached);
+                }
+
+                process_data.detach();
+                return Err(String::from("Process exited"));
+            }
+        }
+        return self.refresh();
+    }
+
+    /// Replaces the dump of the main module with only the range [rva, rva + len) of the module, for example the .text section,
+    /// using the current handle. Scans on the main module then only search that range, which is faster and avoids matches in data.
+    /// Returns false when not attached, when the range is outside the module or when the read fails, leaving the dump unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.dump_main_module_range(0x1000, 0x200000);
+    /// let pointer = process.scan_abs("Error message", "56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8, vec![0, 0, 0])?;
+    /// ```
+    pub fn dump_main_module_range(&mut self, rva: usize, len: usize) -> bool
+    {
+        let mut process_data = self.process_data.borrow_mut();
+        if !process_data.attached || process_data.from_buffer
+        {
+            return false;
+        }
+
+        let handle = process_data.handle.raw();
+        return process_data.main_module.dump_range(handle, rva, len);
+    }
+
+    /// Dumps the memory of the main module again with the current handle, so that scans see code that changed since attaching.
+    /// When only a range of the module was dumped, that same range is dumped again. Does nothing when not attached,
+    /// or when the main module isn't dumped because dump_main_module was disabled and no range was dumped with dump_main_module_range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.redump_main_module();
+    /// let pointer = process.scan_abs("Error message", "56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8, vec![0, 0, 0])?;
+    /// ```
+    pub fn redump_main_module(&mut self)
+    {
+        let mut process_data = self.process_data.borrow_mut();
+        if !process_data.attached || process_data.from_buffer
+        {
+            return;
+        }
+
+        let handle = process_data.handle.raw();
+        let dump_rva = process_data.main_module.dump_rva;
+        let dump_len = process_data.main_module.memory.len();
+        if dump_rva != 0 || (dump_len != 0 && dump_len != process_data.main_module.size)
+        {
+            process_data.main_module.dump_range(handle, dump_rva, dump_len);
+        }
+        else if process_data.dump_main_module
+        {
+            process_data.main_module.dump_memory(handle);
+        }
+    }
+
+    /// Attaches to the process with the given pid, instead of searching for a running process by name. Use this to pick a specific instance
+    /// when multiple copies of the same executable are running. The process is opened with the configured access rights, falling back to a
+    /// read-only attachment like refresh. Returns an error when the pid doesn't exist or can't be opened.
+    /// Once the process exits, refresh searches for a new process by name again, also when the pid was set with ProcessBuilder::pid,
+    /// because windows can reuse the pid for an unrelated process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.attach_pid(1234)?;
+    /// ```
+    pub fn attach_pid(&mut self, pid: u32) -> Result<RefreshStatus, String>
+    {
+        if self.process_data.borrow().from_buffer
+        {
+            return Err(String::from("Can't attach a process created from a buffer"));
+        }
+
+        unsafe
+        {
+            let mut read_only = false;
+            let access_rights = self.process_data.borrow().access_rights;
+            let handle = OpenProcess(access_rights, BOOL(0), pid).or_else(|_|
+            {
+                read_only = true;
+                OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, BOOL(0), pid)
+            }).map_err(|e| format!("Failed to open process {}: {}", pid, e))?;
+
+            let mut mod_name = [0; windows::Win32::Foundation::MAX_PATH as usize];
+            if K32GetModuleFileNameExW(handle, HINSTANCE(0), &mut mod_name) == 0
+            {
+                let _ = CloseHandle(handle);
+                return Err(format!("Failed to get the executable of process {}", pid));
+            }
+
+            //Release a previous attachment before replacing it
+            self.process_data.borrow_mut().detach();
+
+            let file_path = w32str_to_string(&mod_name.to_vec());
+            let file_name = get_file_name_from_string(&file_path);
+            return self.attach_handle(pid, handle, read_only, file_path, file_name);
+        }
+    }
+
+    //Attaches to an opened process: caches its modules, dumps the main module and takes ownership of the handle
+    unsafe fn attach_handle(&mut self, pid: u32, handle: HANDLE, read_only: bool, file_path: String, file_name: String) -> Result<RefreshStatus, String>
+    {
+        let mut modules = Process::get_process_modules(handle);
+        if modules.is_empty()
+        {
+            let _ = CloseHandle(handle);
+            return Err(String::from("Failed to get process modules"));
+        }
+
+        //A process running under WOW64 is a 32 bit process on a 64 bit os
+        let mut is_wow64 = BOOL(0);
+        let _ = IsWow64Process(handle, &mut is_wow64);
+
+        let mut process_data = self.process_data.borrow_mut();
+
+        process_data.id = pid;
+        process_data.handle = ProcessHandle::new(handle);
+        process_data.filename = file_name;
+        process_data.path = file_path;
+        process_data.attached = true;
+        process_data.main_module = modules.remove(0);
+        if process_data.dump_main_module
+        {
+            process_data.main_module.dump_memory(handle);
+        }
+        process_data.modules = modules;
+        process_data.applied_patches.clear();
+        process_data.read_only = read_only;
+        process_data.is_64_bit = cfg!(target_pointer_width = "64") && !is_wow64.as_bool();
+
+        let last_pid = process_data.last_pid;
+        process_data.last_pid = pid;
+        if last_pid != 0 && last_pid != pid
+        {
+            return Ok(RefreshStatus::Reattached);
+        }
+        return Ok(RefreshStatus::Attached);
+    }
+}
@@ -14,15 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::helpers::{scan, to_pattern};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use windows::Win32::System::Memory::{MEM_COMMIT, PAGE_GUARD, PAGE_NOACCESS, PAGE_PROTECTION_FLAGS};
+use crate::helpers::{pattern_byte_matches, read_displacement, scan, scan_all, scan_many, to_pattern, to_pattern_masked, PatternByte};
 use crate::pointer::Pointer;
 use crate::prelude::*;
+use crate::process_data::ProcessData;
 
 impl Process
 {
     /// Does an absolute scan (for x86 targets or for process code) where the target pointer is absolute
     /// Takes a list of offsets to create pointer jumps down a bigger complex structure.
     /// Pointers implement memory reading and writing.
+    /// The scan runs against the dump of the main module, use scan_abs_verified to also check the match against the live process.
     ///
     /// # Examples
     ///
@@ -35,23 +41,282 @@ impl Process
     /// ```
     pub fn scan_abs(&self, error_name: &str, pattern: &str, scan_offset: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
     {
-        let byte_pattern = to_pattern(pattern);
-        let scan_result = scan(&self.process_data.borrow().main_module.memory, &byte_pattern);
+        return self.scan_abs_pattern(error_name, &to_pattern(pattern), scan_offset, pointer_offsets);
+    }
+
+    /// Does an absolute scan like scan_abs, with the pattern given as a byte array and an IDA/x64dbg style mask.
+    /// x or X in the mask keeps the byte at that position, . or ? is a wildcard. Fails when the lengths don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_abs_masked("Error message", b"\x56\x8B\xF1\x8B\x46\x1C\x50\xA1\x00\x00\x00\x00\x32\xC9", "xxxxxxxx????xx", 8, vec![0, 0, 0])?;
+    /// ```
+    pub fn scan_abs_masked(&self, error_name: &str, bytes: &[u8], mask: &str, scan_offset: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let byte_pattern = to_pattern_masked(bytes, mask).map_err(|e| format!("{}: {}", error_name, e))?;
+        return self.scan_abs_pattern(error_name, &byte_pattern, scan_offset, pointer_offsets);
+    }
+
+    fn scan_abs_pattern(&self, error_name: &str, byte_pattern: &[PatternByte], scan_offset: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let scan_result = scan(&self.process_data.borrow().main_module.memory, byte_pattern);
         if scan_result.is_none()
         {
             return Err(String::from(format!("Scan failed: {}", error_name)));
         }
 
         let mut address = scan_result.unwrap();
-        address += self.process_data.borrow().main_module.base_address;
+        address += self.process_data.borrow().main_module.get_dump_address();
         address += scan_offset;
-        return Ok(Pointer::new(self.process_data.clone(), true, address, pointer_offsets));
+        return Ok(Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets));
+    }
+
+    /// Does an absolute scan like scan_abs, and also returns the matched bytes with up to context bytes before and after the match
+    /// from the main module dump, to help verify that the right match was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some((pointer, bytes)) = process.scan_abs_context("56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8, 16)
+    /// {
+    ///     println!("{:#x}: {:02x?}", pointer.get_base_address(), bytes);
+    /// }
+    /// ```
+    pub fn scan_abs_context(&self, pattern: &str, scan_offset: usize, context: usize) -> Option<(Pointer, Vec<u8>)>
+    {
+        let byte_pattern = to_pattern(pattern);
+        let process_data = self.process_data.borrow();
+        let memory = &process_data.main_module.memory;
+
+        let index = scan(memory, &byte_pattern)?;
+        let start = index.saturating_sub(context);
+        let end = (index + byte_pattern.len() + context).min(memory.len());
+
+        let address = process_data.main_module.get_dump_address() + index + scan_offset;
+        return Some((Pointer::new(self.process_data.clone(), process_data.is_64_bit, address, Vec::new()), memory[start..end].to_vec()));
+    }
+
+    /// Does an absolute scan like scan_abs, but returns a pointer for every match instead of only the first.
+    /// Stops after limit matches when a limit is given, to bound memory use for patterns that match very often.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointers = process.scan_abs_all("56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8, vec![0, 0, 0], Some(100));
+    /// ```
+    pub fn scan_abs_all(&self, pattern: &str, scan_offset: usize, pointer_offsets: Vec<usize>, limit: Option<usize>) -> Vec<Pointer>
+    {
+        let byte_pattern = to_pattern(pattern);
+        let scan_results = scan_all(&self.process_data.borrow().main_module.memory, &byte_pattern, limit);
+        let base_address = self.process_data.borrow().main_module.get_dump_address();
+        let is_64_bit = self.process_data.borrow().is_64_bit;
+
+        let mut result = Vec::new();
+        for address in scan_results
+        {
+            result.push(Pointer::new(self.process_data.clone(), is_64_bit, base_address + address + scan_offset, pointer_offsets.clone()));
+        }
+        return result;
+    }
+
+    /// Scans the main module lazily, yielding the absolute address of each match one at a time.
+    /// Each step resumes the scan from the last match + 1, so no list of matches is allocated and consumers can stop early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// for address in process.scan_iter("56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9").take(10)
+    /// {
+    ///     println!("{:#x}", address);
+    /// }
+    /// ```
+    pub fn scan_iter<'a>(&'a self, pattern: &str) -> impl Iterator<Item = usize> + 'a
+    {
+        let byte_pattern = to_pattern(pattern);
+        let mut position = 0;
+
+        return std::iter::from_fn(move ||
+        {
+            let process_data = self.process_data.borrow();
+            let memory = &process_data.main_module.memory;
+            if position >= memory.len()
+            {
+                return None;
+            }
+
+            let index = scan(&memory[position..], &byte_pattern);
+            if index.is_none()
+            {
+                position = memory.len();
+                return None;
+            }
+
+            let offset = position + index.unwrap();
+            position = offset + 1;
+            return Some(process_data.main_module.get_dump_address() + offset);
+        });
+    }
+
+    /// Scans all committed memory regions of the attached process whose protection matches one of the given protection flags,
+    /// such as PAGE_READWRITE | PAGE_WRITECOPY for heap and data. Guard pages are skipped.
+    /// Returns the absolute addresses of the matches. Stops after limit matches when a limit is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    /// use windows::Win32::System::Memory::{PAGE_READWRITE, PAGE_WRITECOPY};
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let addresses = process.scan_abs_all_regions("e8 03 00 00", PAGE_READWRITE | PAGE_WRITECOPY, Some(1000));
+    /// ```
+    pub fn scan_abs_all_regions(&self, pattern: &str, protection: PAGE_PROTECTION_FLAGS, limit: Option<usize>) -> Vec<usize>
+    {
+        let byte_pattern = to_pattern(pattern);
+        let mut result = Vec::new();
+
+        for region in self.get_memory_regions()
+        {
+            if region.state != MEM_COMMIT || (region.protect & protection).0 == 0 || (region.protect & PAGE_GUARD).0 != 0
+            {
+                continue;
+            }
+
+            let mut memory = vec![0u8; region.region_size];
+            if !self.read_memory_abs(region.base_address, &mut memory)
+            {
+                continue;
+            }
+
+            let remaining = limit.map(|l| l - result.len());
+            for address in scan_all(&memory, &byte_pattern, remaining)
+            {
+                result.push(region.base_address + address);
+            }
+
+            if limit.is_some() && result.len() >= limit.unwrap()
+            {
+                break;
+            }
+        }
+        return result;
+    }
+
+    /// Scans the live memory of the attached process in [start, end), for signatures on the heap or in generated code that is not part of a module.
+    /// Memory is read a page at a time, regions that are not committed, not accessible or guarded are skipped, as are pages that fail to read.
+    /// Matches that straddle two contiguous pages are found. Returns the absolute address of the first match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let address = process.scan_range(0x20000000, 0x30000000, "48 8b 05 ? ? ? ? 48 8b 50 10");
+    /// ```
+    pub fn scan_range(&self, start: usize, end: usize, pattern: &str) -> Option<usize>
+    {
+        const PAGE_SIZE: usize = 0x1000;
+
+        let byte_pattern = to_pattern(pattern);
+        let overlap = byte_pattern.len().saturating_sub(1);
+
+        //Bytes at the end of the previous page, kept to find matches that straddle into the next page
+        let mut carry: Vec<u8> = Vec::new();
+        let mut carry_end = 0;
+
+        let mut address = start;
+        while address < end
+        {
+            let region = self.query_address(address)?;
+            if region.region_size == 0
+            {
+                return None;
+            }
+            let region_end = usize::min(region.base_address.checked_add(region.region_size)?, end);
+
+            if region.state != MEM_COMMIT || region.protect == PAGE_NOACCESS || (region.protect & PAGE_GUARD).0 != 0
+            {
+                address = region_end;
+                continue;
+            }
+
+            while address < region_end
+            {
+                //Align chunks to pages, the first one might start in the middle of a page
+                let chunk_end = usize::min((address / PAGE_SIZE + 1) * PAGE_SIZE, region_end);
+                let mut page = vec![0u8; chunk_end - address];
+                if self.read_memory_abs(address, &mut page)
+                {
+                    if carry_end != address
+                    {
+                        carry.clear();
+                    }
+                    let buffer_address = address - carry.len();
+                    carry.extend_from_slice(&page);
+
+                    if let Some(index) = scan(&carry, &byte_pattern)
+                    {
+                        return Some(buffer_address + index);
+                    }
+
+                    carry.drain(..carry.len().saturating_sub(overlap));
+                    carry_end = chunk_end;
+                }
+                address = chunk_end;
+            }
+        }
+        return None;
+    }
+
+    /// Does an absolute scan like scan_abs, but re-reads each match from the live process and only accepts it when the live bytes
+    /// still match the pattern. Catches code that was patched at runtime after the main module was dumped; on mismatch the scan continues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_abs_verified("Error message", "56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8, vec![0, 0, 0])?;
+    /// ```
+    pub fn scan_abs_verified(&self, error_name: &str, pattern: &str, scan_offset: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let scan_result = self.scan_verified(&to_pattern(pattern));
+        if scan_result.is_none()
+        {
+            return Err(String::from(format!("Scan failed: {}", error_name)));
+        }
+
+        let address = scan_result.unwrap() + scan_offset;
+        return Ok(Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets));
     }
 
     /// Does a relative scan (for x64 targets) where the target pointer is located relative to instruction's
     /// size and location.
     /// Takes a list of offsets to create pointer jumps down a bigger complex structure.
     /// Pointers implement memory reading and writing.
+    /// The scan runs against the dump of the main module, use scan_rel_verified to also check the match against the live process.
     ///
     /// # Examples
     ///
@@ -63,19 +328,499 @@ impl Process
     /// let pointer = process.scan_rel("Error message", "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0])?;
     /// ```
     pub fn scan_rel(&self, error_name: &str, pattern: &str, scan_offset: usize, instruction_size: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        return Process::scan_rel_with_data(&self.process_data, error_name, pattern, scan_offset, instruction_size, 4, pointer_offsets);
+    }
+
+    /// Does a relative scan like scan_rel, but with a displacement of displacement_size bytes (1, 2, 4 or 8) instead of 4.
+    /// Use a size of 1 for rel8 jumps. The displacement is sign-extended, so targets before the instruction resolve correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_rel_sized("Error message", "eb ? 48 8b 50 10 48 89 54 24 60", 1, 2, 1, vec![0])?;
+    /// ```
+    pub fn scan_rel_sized(&self, error_name: &str, pattern: &str, scan_offset: usize, instruction_size: usize, displacement_size: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        return Process::scan_rel_with_data(&self.process_data, error_name, pattern, scan_offset, instruction_size, displacement_size, pointer_offsets);
+    }
+
+    pub(crate) fn scan_rel_with_data(process_data_rc: &Rc<RefCell<ProcessData>>, error_name: &str, pattern: &str, scan_offset: usize, instruction_size: usize, displacement_size: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let process_data = process_data_rc.borrow();
+
+        let byte_pattern = to_pattern(pattern);
+        let scan_result = scan(&process_data.main_module.memory, &byte_pattern);
+        if scan_result.is_none()
+        {
+            return Err(String::from(format!("Scan failed: {}", error_name)));
+        }
+
+        let address = scan_result.unwrap();
+
+        //Read the displacement from the dump instead of the live process, these are the same bytes that were just matched
+        let displacement = process_data.main_module.memory.get(address + scan_offset..).and_then(|bytes| read_displacement(bytes, displacement_size));
+        if displacement.is_none()
+        {
+            return Err(String::from(format!("Scan failed: {}", error_name)));
+        }
+        let result = (process_data.main_module.get_dump_address() + address + instruction_size).wrapping_add_signed(displacement.unwrap()); //Relative jump
+
+        return Ok(Pointer::new(process_data_rc.clone(), process_data.is_64_bit, result, pointer_offsets));
+    }
+
+    /// Does an absolute scan like scan_abs, but in the module with the given name (case insensitive) instead of the main module.
+    /// The module is dumped on first use and the dump is cached until the modules are enumerated again. Fails when the module isn't loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_abs_in_module("Error message", "kernel32.dll", "48 83 ec 28 48 8b 0d ? ? ? ?", 0, vec![])?;
+    /// ```
+    pub fn scan_abs_in_module(&self, error_name: &str, module_name: &str, pattern: &str, scan_offset: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let byte_pattern = to_pattern(pattern);
+        let address = self.with_module_dump(module_name, |memory, dump_address|
+        {
+            return scan(memory, &byte_pattern).map(|index| dump_address + index + scan_offset);
+        })?;
+
+        return match address
+        {
+            Some(address) => Ok(Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets)),
+            None => Err(String::from(format!("Scan failed: {}", error_name))),
+        };
+    }
+
+    /// Does a relative scan like scan_rel, but in the module with the given name (case insensitive) instead of the main module.
+    /// The module is dumped on first use and the dump is cached until the modules are enumerated again. Fails when the module isn't loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_rel_in_module("Error message", "kernel32.dll", "48 8b 0d ? ? ? ? 48 85 c9", 3, 7, vec![0])?;
+    /// ```
+    pub fn scan_rel_in_module(&self, error_name: &str, module_name: &str, pattern: &str, scan_offset: usize, instruction_size: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
     {
         let byte_pattern = to_pattern(pattern);
-        let scan_result = scan(&self.process_data.borrow().main_module.memory, &byte_pattern);
+        let address = self.with_module_dump(module_name, |memory, dump_address|
+        {
+            let index = scan(memory, &byte_pattern)?;
+            let displacement = read_displacement(memory.get(index + scan_offset..)?, 4)?;
+            return Some((dump_address + index + instruction_size).wrapping_add_signed(displacement)); //Relative jump
+        })?;
+
+        return match address
+        {
+            Some(address) => Ok(Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets)),
+            None => Err(String::from(format!("Scan failed: {}", error_name))),
+        };
+    }
+
+    //Finds a module by name, dumps it when it has not been dumped yet and passes its memory and dump address to f
+    fn with_module_dump<R, F: FnOnce(&[u8], usize) -> R>(&self, module_name: &str, f: F) -> Result<R, String>
+    {
+        let mut process_data = self.process_data.borrow_mut();
+        let lowercase_name = module_name.to_lowercase();
+        if process_data.main_module.name.to_lowercase() == lowercase_name
+        {
+            return Ok(f(&process_data.main_module.memory, process_data.main_module.get_dump_address()));
+        }
+
+        let handle = process_data.handle.raw();
+        let from_buffer = process_data.from_buffer;
+        let module = process_data.modules.iter_mut().find(|module| module.name.to_lowercase() == lowercase_name);
+        if module.is_none()
+        {
+            return Err(String::from(format!("Module not loaded: {}", module_name)));
+        }
+
+        let module = module.unwrap();
+        if module.memory.is_empty() && !from_buffer
+        {
+            module.dump_memory(handle);
+        }
+        return Ok(f(&module.memory, module.get_dump_address()));
+    }
+
+    /// Creates a pointer whose base address is the result of a relative scan (see scan_rel). The scan is stored and
+    /// re-evaluated automatically when the process is re-attached, so the pointer survives restarts of the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// let game_data_man = process.scan_pointer("48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0]);
+    /// loop
+    /// {
+    ///     let _ = process.refresh();
+    ///     let igt = game_data_man.read_u32_rel(Some(0xa4));
+    /// }
+    /// ```
+    pub fn scan_pointer(&self, pattern: &str, scan_offset: usize, instruction_size: usize, pointer_offsets: Vec<usize>) -> ScanPointer
+    {
+        return ScanPointer::new(self.process_data.clone(), pattern, scan_offset, instruction_size, pointer_offsets);
+    }
+
+    /// Does multiple absolute scans in a single pass over the main module.
+    /// Takes a list of (pattern, scan_offset) pairs and returns a pointer for each pattern that was found,
+    /// in the same order as the patterns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointers = process.scan_many(&[("56 8B F1 8B 46 1C 50 A1 ? ? ? ? 32 C9", 8), ("48 8b 05 ? ? ? ? 48 8b 50 10", 3)]);
+    /// ```
+    pub fn scan_many(&self, patterns: &[(&str, usize)]) -> Vec<Option<Pointer>>
+    {
+        let byte_patterns: Vec<Vec<PatternByte>> = patterns.iter().map(|(pattern, _)| to_pattern(pattern)).collect();
+        let scan_results = scan_many(&self.process_data.borrow().main_module.memory, &byte_patterns);
+        let base_address = self.process_data.borrow().main_module.get_dump_address();
+        let is_64_bit = self.process_data.borrow().is_64_bit;
+
+        let mut result = Vec::new();
+        for i in 0..patterns.len()
+        {
+            match scan_results[i]
+            {
+                Some(address) => result.push(Some(Pointer::new(self.process_data.clone(), is_64_bit, base_address + address + patterns[i].1, Vec::new()))),
+                None => result.push(None),
+            }
+        }
+        return result;
+    }
+
+    /// Does a relative scan like scan_rel, but re-reads each match from the live process and only accepts it when the live bytes
+    /// still match the pattern. The displacement is also read from the live process. On mismatch the scan continues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.scan_rel_verified("Error message", "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0])?;
+    /// ```
+    pub fn scan_rel_verified(&self, error_name: &str, pattern: &str, scan_offset: usize, instruction_size: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        let scan_result = self.scan_verified(&to_pattern(pattern));
         if scan_result.is_none()
         {
             return Err(String::from(format!("Scan failed: {}", error_name)));
         }
 
         let address = scan_result.unwrap();
-        let address_value = self.read_u32_rel(Some(address + scan_offset));
-        let result = self.process_data.borrow().main_module.base_address + address + instruction_size + address_value as usize; //Relative jump
+        let mut buffer = [0; 4];
+        if !self.read_memory_abs(address + scan_offset, &mut buffer)
+        {
+            return Err(String::from(format!("Scan failed: {}", error_name)));
+        }
+        let result = (address + instruction_size).wrapping_add_signed(i32::from_le_bytes(buffer) as isize); //Relative jump
 
-        return Ok(Pointer::new(self.process_data.clone(), true, result, pointer_offsets));
+        return Ok(Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, result, pointer_offsets));
+    }
+
+    //Finds the first match in the main module dump whose bytes in the live process still match the pattern, returns its absolute address
+    fn scan_verified(&self, byte_pattern: &[PatternByte]) -> Option<usize>
+    {
+        let mut position = 0;
+        loop
+        {
+            let address =
+            {
+                let process_data = self.process_data.borrow();
+                let memory = &process_data.main_module.memory;
+                if position >= memory.len()
+                {
+                    return None;
+                }
+
+                let index = scan(&memory[position..], byte_pattern)?;
+                position += index + 1;
+                process_data.main_module.get_dump_address() + position - 1
+            };
+
+            let mut live = vec![0u8; byte_pattern.len()];
+            if self.read_memory_abs(address, &mut live) && byte_pattern.iter().zip(live.iter()).all(|(p, b)| pattern_byte_matches(p, *b))
+            {
+                return Some(address);
+            }
+        }
+    }
+
+    /// Resolves every signature in a signature set with scan_abs or scan_rel, depending on the kind of the signature.
+    /// Returns the result of each scan by signature name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut set = SignatureSet::default();
+    /// set.insert("GameDataMan", Signature
+    /// {
+    ///     kind: SignatureKind::Rel,
+    ///     pattern: String::from("48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60"),
+    ///     scan_offset: 3,
+    ///     instruction_size: 7,
+    ///     offsets: vec![0],
+    /// });
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointers = process.scan_set(&set);
+    /// if let Some(Ok(game_data_man)) = pointers.get("GameDataMan")
+    /// {
+    ///     let igt = game_data_man.read_u32_rel(Some(0xa4));
+    /// }
+    /// ```
+    pub fn scan_set(&self, set: &SignatureSet) -> HashMap<String, Result<Pointer, String>>
+    {
+        let mut result = HashMap::new();
+        for (name, signature) in &set.signatures
+        {
+            let pointer = match signature.kind
+            {
+                SignatureKind::Abs => self.scan_abs(name, &signature.pattern, signature.scan_offset, signature.offsets.clone()),
+                SignatureKind::Rel => self.scan_rel(name, &signature.pattern, signature.scan_offset, signature.instruction_size, signature.offsets.clone()),
+            };
+            result.insert(name.clone(), pointer);
+        }
+        return result;
+    }
+
+    /// Scans the main module for every place where the target address itself is stored, as an 8 byte value in a 64 bit process
+    /// or a 4 byte value in a 32 bit process. Returns a pointer to each location where the address is stored.
+    /// For code that references the target with a relative displacement, use find_references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// for pointer in process.scan_pointer_value(0x141c04a08)
+    /// {
+    ///     println!("{:#x}", pointer.get_base_address());
+    /// }
+    /// ```
+    pub fn scan_pointer_value(&self, target: usize) -> Vec<Pointer>
+    {
+        let process_data = self.process_data.borrow();
+        let bytes = if process_data.is_64_bit { (target as u64).to_le_bytes().to_vec() } else { (target as u32).to_le_bytes().to_vec() };
+        let byte_pattern: Vec<PatternByte> = bytes.into_iter().map(|b| (b, 0xFF)).collect();
+        let base_address = process_data.main_module.get_dump_address();
+
+        let mut result = Vec::new();
+        for address in scan_all(&process_data.main_module.memory, &byte_pattern, None)
+        {
+            result.push(Pointer::new(self.process_data.clone(), process_data.is_64_bit, base_address + address, Vec::new()));
+        }
+        return result;
+    }
+
+    /// Walks the main module, interpreting every 4 byte window as a u32, and returns the absolute addresses
+    /// of all values for which the predicate returns true. Useful for range, epsilon or bitmask value scans.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let addresses = process.scan_predicate_u32(|value| value >= 100 && value <= 200);
+    /// ```
+    pub fn scan_predicate_u32(&self, pred: impl Fn(u32) -> bool) -> Vec<usize>
+    {
+        let process_data = self.process_data.borrow();
+        let memory = &process_data.main_module.memory;
+
+        let mut result = Vec::new();
+        if memory.len() < 4
+        {
+            return result;
+        }
+
+        for i in 0..memory.len() - 3
+        {
+            let value = u32::from_le_bytes(memory[i..i + 4].try_into().unwrap());
+            if pred(value)
+            {
+                result.push(process_data.main_module.get_dump_address() + i);
+            }
+        }
+        return result;
+    }
+
+    /// Finds code in the main module that references the target address and returns the absolute addresses of the references.
+    /// Looks for 4 byte RIP-relative displacements (x64) that resolve to the target, assuming the displacement is the last part
+    /// of the instruction, and for the absolute 4 byte encoding of the target (x86) when the target fits in 32 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let references = process.find_references(0x141c04a08);
+    /// ```
+    pub fn find_references(&self, target_address: usize) -> Vec<usize>
+    {
+        let process_data = self.process_data.borrow();
+        let memory = &process_data.main_module.memory;
+        let base_address = process_data.main_module.get_dump_address();
+
+        let mut result = Vec::new();
+        if memory.len() < 4
+        {
+            return result;
+        }
+
+        for i in 0..memory.len() - 3
+        {
+            let bytes: [u8; 4] = memory[i..i + 4].try_into().unwrap();
+            let address = base_address + i;
+
+            //RIP-relative, relative to the end of the instruction
+            let displacement = i32::from_le_bytes(bytes) as isize;
+            let rip_relative = (address + 4).wrapping_add_signed(displacement);
+
+            //Absolute
+            let absolute = u32::from_le_bytes(bytes) as usize;
+
+            if rip_relative == target_address || (target_address <= u32::MAX as usize && absolute == target_address)
+            {
+                result.push(address);
+            }
+        }
+        return result;
+    }
+
+    /// Reads the call/jmp instruction at an absolute address and returns the address it jumps to.
+    /// Recognizes E8 (call rel32), E9 (jmp rel32) and EB (jmp rel8). Returns None for other instructions or when the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let function = process.read_jump_target(0x140123456);
+    /// ```
+    pub fn read_jump_target(&self, instruction_address: usize) -> Option<usize>
+    {
+        let mut buffer = [0; 5];
+        if !self.read_memory_abs(instruction_address, &mut buffer[..2])
+        {
+            return None;
+        }
+
+        match buffer[0]
+        {
+            0xE8 | 0xE9 =>
+            {
+                if !self.read_memory_abs(instruction_address, &mut buffer)
+                {
+                    return None;
+                }
+                let displacement = i32::from_le_bytes(buffer[1..5].try_into().unwrap()) as isize;
+                return Some((instruction_address + 5).wrapping_add_signed(displacement));
+            }
+            0xEB =>
+            {
+                let displacement = buffer[1] as i8 as isize;
+                return Some((instruction_address + 2).wrapping_add_signed(displacement));
+            }
+            _ => return None,
+        }
+    }
+
+    /// Generates a signature for an address in the main module that is unique in the main module dump, for authoring signatures.
+    /// Bytes that look like call/jump targets, relative displacements or absolute addresses inside the module are replaced by wildcards,
+    /// so that the signature survives relocation and small code changes. The signature is grown byte by byte from the address until it is unique,
+    /// up to max_len bytes. Returns None when no unique signature was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// if let Some(signature) = process.make_signature(0x140123456, 64)
+    /// {
+    ///     println!("{}", signature);
+    /// }
+    /// ```
+    pub fn make_signature(&self, address: usize, max_len: usize) -> Option<String>
+    {
+        let process_data = self.process_data.borrow();
+        let module = &process_data.main_module;
+        let memory = &module.memory;
+
+        let start = address.checked_sub(module.get_dump_address())?;
+        if start >= memory.len()
+        {
+            return None;
+        }
+        let bytes = &memory[start..start.saturating_add(max_len).min(memory.len())];
+        let module_range = module.base_address..module.base_address.saturating_add(module.size);
+
+        let mut pattern: Vec<PatternByte> = bytes.iter().map(|b| (*b, 0xFF)).collect();
+        for i in 0..bytes.len().saturating_sub(3)
+        {
+            let value = i32::from_le_bytes(bytes[i..i + 4].try_into().unwrap());
+            let after_call = i > 0 && (bytes[i - 1] == 0xE8 || bytes[i - 1] == 0xE9);
+            let relative = value.unsigned_abs() > 0xff && module_range.contains(&(address + i + 4).wrapping_add_signed(value as isize));
+            let absolute = module_range.contains(&(value as u32 as usize));
+            if after_call || relative || absolute
+            {
+                pattern[i..i + 4].fill((0, 0));
+            }
+        }
+
+        for len in 1..=pattern.len()
+        {
+            //Don't end a signature in a wildcard, it doesn't make it more unique
+            if pattern[len - 1].1 == 0
+            {
+                continue;
+            }
+
+            if scan_all(memory, &pattern[..len], Some(2)).len() == 1
+            {
+                let signature: Vec<String> = pattern[..len].iter().map(|(byte, mask)| match mask
+                {
+                    0 => String::from("?"),
+                    _ => format!("{:02x}", byte),
+                }).collect();
+                return Some(signature.join(" "));
+            }
+        }
+        return None;
     }
 
     /// Create a pointer without scanning from an absolute address and a list of offsets.
@@ -95,6 +840,94 @@ impl Process
     /// ```
     pub fn create_pointer(&self, address: usize, pointer_offsets: Vec<usize>) -> Pointer
     {
-        return Pointer::new(self.process_data.clone(), true, address, pointer_offsets);
+        return Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets);
+    }
+
+    /// Create a pointer without scanning from an absolute address and a slice of offsets, like create_pointer.
+    /// Short offset lists are stored inline, so no heap allocation is needed for the offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer_slice(0x1234, &[0xc, 0x10]);
+    /// ```
+    pub fn create_pointer_slice(&self, address: usize, pointer_offsets: &[usize]) -> Pointer
+    {
+        return Pointer::new_from_slice(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, pointer_offsets);
+    }
+
+    /// Create a pointer without scanning from an absolute address and a list of offset names, which are looked up in the offset table.
+    /// The names are shown next to the offsets when debug logging is enabled on the pointer. Returns an error when a name is not in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut table = OffsetTable::default();
+    /// table.insert("player_ins", 0x68);
+    /// table.insert("health", 0x3e8);
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_named_pointer(0x1234, &["player_ins", "health"], &table)?;
+    /// ```
+    pub fn create_named_pointer(&self, address: usize, offset_names: &[&str], table: &OffsetTable) -> Result<Pointer, String>
+    {
+        let mut offsets = Vec::new();
+        for name in offset_names
+        {
+            offsets.push(table.get(name).ok_or(format!("Unknown offset: {}", name))?);
+        }
+
+        let mut pointer = Pointer::new(self.process_data.clone(), self.process_data.borrow().is_64_bit, address, offsets);
+        pointer.set_offset_names(offset_names.iter().map(|name| String::from(*name)).collect());
+        return Ok(pointer);
+    }
+
+    /// Associate a pointer with this process, so that it reads from and writes to this process.
+    /// Used for pointers that were not created by this process, for example pointers deserialized with the serde feature.
+    ///
+    /// # Examples
+    ///
+    /// The example uses serde_json, which is not a dependency of mem-rs:
+    ///
+    /// ```ignore
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let saved_pointer = std::fs::read_to_string("pointer.json")?;
+    /// let mut pointer: Pointer = serde_json::from_str(&saved_pointer)?;
+    /// process.associate_pointer(&mut pointer);
+    /// ```
+    pub fn associate_pointer(&self, pointer: &mut Pointer)
+    {
+        pointer.set_process_data(self.process_data.clone());
+    }
+
+    /// Create a pointer without scanning, like create_pointer, but verify that the address falls within committed memory
+    /// of the attached process. Catches mistakes like passing a relative address where an absolute address was expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer_checked(0x141c04a08, vec![0xc, 0x10])?;
+    /// ```
+    pub fn create_pointer_checked(&self, address: usize, pointer_offsets: Vec<usize>) -> Result<Pointer, String>
+    {
+        match self.query_address(address)
+        {
+            Some(info) if info.state == MEM_COMMIT => return Ok(self.create_pointer(address, pointer_offsets)),
+            _ => return Err(format!("Address {:#x} is not in committed memory", address)),
+        }
     }
 }
\ No newline at end of file
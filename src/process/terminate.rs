@@ -0,0 +1,62 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_TERMINATE, TerminateProcess};
+use crate::prelude::Process;
+
+impl Process
+{
+    /// Terminates the attached process with the given exit code and detaches from it.
+    /// A separate handle with PROCESS_TERMINATE access is opened, since the attached handle does not have that right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.terminate(0)?;
+    /// ```
+    pub fn terminate(&self, exit_code: u32) -> Result<(), String>
+    {
+        if !self.is_attached() || self.process_data.borrow().from_buffer
+        {
+            return Err(String::from("Process not attached"));
+        }
+
+        unsafe
+        {
+            let handle = OpenProcess(PROCESS_TERMINATE, false, self.process_data.borrow().id);
+            if handle.is_err()
+            {
+                return Err(String::from("Failed to open process for termination"));
+            }
+            let handle = handle.unwrap();
+
+            let result = TerminateProcess(handle, exit_code);
+            let _ = CloseHandle(handle);
+            if result.is_err()
+            {
+                return Err(String::from("Failed to terminate process"));
+            }
+        }
+
+        self.process_data.borrow_mut().detach();
+        return Ok(());
+    }
+}
@@ -0,0 +1,107 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::System::Diagnostics::Debug::{CONTEXT, CONTEXT_FLAGS, GetThreadContext, SetThreadContext};
+use windows::Win32::System::Threading::{OpenThread, THREAD_GET_CONTEXT, THREAD_SET_CONTEXT};
+use crate::prelude::Process;
+
+#[cfg(target_arch = "x86_64")]
+const CONTEXT_ALL: CONTEXT_FLAGS = windows::Win32::System::Diagnostics::Debug::CONTEXT_ALL_AMD64;
+#[cfg(target_arch = "x86")]
+const CONTEXT_ALL: CONTEXT_FLAGS = windows::Win32::System::Diagnostics::Debug::CONTEXT_ALL_X86;
+#[cfg(target_arch = "aarch64")]
+const CONTEXT_ALL: CONTEXT_FLAGS = windows::Win32::System::Diagnostics::Debug::CONTEXT_ALL_ARM64;
+
+//GetThreadContext requires a 16 byte aligned CONTEXT on x64
+#[repr(C, align(16))]
+struct AlignedContext(CONTEXT);
+
+impl Process
+{
+    /// Reads the context (registers) of a thread. The thread should be suspended for the result to be meaningful.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let context = process.get_thread_context(1234)?;
+    /// println!("rip: {:#x}", context.Rip);
+    /// ```
+    pub fn get_thread_context(&self, thread_id: u32) -> Result<CONTEXT, String>
+    {
+        unsafe
+        {
+            let thread = OpenThread(THREAD_GET_CONTEXT, false, thread_id);
+            if thread.is_err()
+            {
+                return Err(String::from("Failed to open thread"));
+            }
+            let thread = thread.unwrap();
+
+            let mut context = AlignedContext(CONTEXT::default());
+            context.0.ContextFlags = CONTEXT_ALL;
+            let result = GetThreadContext(thread, &mut context.0);
+            let _ = CloseHandle(thread);
+
+            if result.is_err()
+            {
+                return Err(String::from("Failed to get thread context"));
+            }
+            return Ok(context.0);
+        }
+    }
+
+    /// Writes the context (registers) of a thread. The thread should be suspended.
+    /// The ContextFlags of the given context determine which registers are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let mut context = process.get_thread_context(1234)?;
+    /// context.Rax = 0;
+    /// process.set_thread_context(1234, &context)?;
+    /// ```
+    pub fn set_thread_context(&self, thread_id: u32, context: &CONTEXT) -> Result<(), String>
+    {
+        unsafe
+        {
+            let thread = OpenThread(THREAD_SET_CONTEXT, false, thread_id);
+            if thread.is_err()
+            {
+                return Err(String::from("Failed to open thread"));
+            }
+            let thread = thread.unwrap();
+
+            let aligned = AlignedContext(*context);
+            let result = SetThreadContext(thread, &aligned.0);
+            let _ = CloseHandle(thread);
+
+            if result.is_err()
+            {
+                return Err(String::from("Failed to set thread context"));
+            }
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::mem::size_of;
+use windows::Win32::Foundation::{CloseHandle, FILETIME};
+use windows::Win32::System::Diagnostics::ToolHelp::{CreateToolhelp32Snapshot, TH32CS_SNAPTHREAD, Thread32First, Thread32Next, THREADENTRY32};
+use windows::Win32::System::Threading::{GetThreadTimes, OpenThread, ResumeThread, THREAD_QUERY_LIMITED_INFORMATION, THREAD_SUSPEND_RESUME};
+use crate::prelude::Process;
+
+impl Process
+{
+    /// Returns the id of the main thread of the attached process, which is the thread that was created first.
+    /// Returns None when not attached or when the threads can't be enumerated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let main_thread_id = process.get_main_thread_id();
+    /// ```
+    pub fn get_main_thread_id(&self) -> Option<u32>
+    {
+        if !self.is_attached()
+        {
+            return None;
+        }
+
+        let pid = self.process_data.borrow().id;
+        let mut result: Option<(u32, u64)> = None;
+
+        unsafe
+        {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0).ok()?;
+
+            let mut entry = THREADENTRY32 { dwSize: size_of::<THREADENTRY32>() as u32, ..Default::default() };
+            let mut next = Thread32First(snapshot, &mut entry);
+            while next.is_ok()
+            {
+                if entry.th32OwnerProcessID == pid
+                {
+                    let creation_time = Process::get_thread_creation_time(entry.th32ThreadID).unwrap_or(u64::MAX);
+                    if result.is_none() || creation_time < result.unwrap().1
+                    {
+                        result = Some((entry.th32ThreadID, creation_time));
+                    }
+                }
+                next = Thread32Next(snapshot, &mut entry);
+            }
+
+            let _ = CloseHandle(snapshot);
+        }
+        return result.map(|(thread_id, _)| thread_id);
+    }
+
+    /// Resumes a suspended thread, such as the main thread of a process that was created suspended.
+    /// Returns the previous suspend count of the thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.inject_dll(r#"C:\temp\native.dll"#)?;
+    /// process.resume_thread(process.get_main_thread_id().unwrap())?;
+    /// ```
+    pub fn resume_thread(&self, thread_id: u32) -> Result<u32, String>
+    {
+        unsafe
+        {
+            let thread = OpenThread(THREAD_SUSPEND_RESUME, false, thread_id);
+            if thread.is_err()
+            {
+                return Err(String::from("Failed to open thread"));
+            }
+            let thread = thread.unwrap();
+
+            let previous_suspend_count = ResumeThread(thread);
+            let _ = CloseHandle(thread);
+
+            if previous_suspend_count == u32::MAX
+            {
+                return Err(String::from("Failed to resume thread"));
+            }
+            return Ok(previous_suspend_count);
+        }
+    }
+
+    fn get_thread_creation_time(thread_id: u32) -> Option<u64>
+    {
+        unsafe
+        {
+            let thread = OpenThread(THREAD_QUERY_LIMITED_INFORMATION, false, thread_id).ok()?;
+
+            let mut creation_time = FILETIME::default();
+            let mut exit_time = FILETIME::default();
+            let mut kernel_time = FILETIME::default();
+            let mut user_time = FILETIME::default();
+            let result = GetThreadTimes(thread, &mut creation_time, &mut exit_time, &mut kernel_time, &mut user_time);
+            let _ = CloseHandle(thread);
+
+            if result.is_err()
+            {
+                return None;
+            }
+            return Some(((creation_time.dwHighDateTime as u64) << 32) | creation_time.dwLowDateTime as u64);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, FindWindowW, GetWindowTextW, GetWindowThreadProcessId, IsWindowVisible};
+use crate::prelude::{Process, RefreshStatus};
+
+struct EnumWindowsData
+{
+    pid: u32,
+    windows: Vec<HWND>,
+}
+
+unsafe extern "system" fn enum_windows_callback(hwnd: HWND, lparam: LPARAM) -> BOOL
+{
+    let data = &mut *(lparam.0 as *mut EnumWindowsData);
+
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if pid == data.pid && IsWindowVisible(hwnd).as_bool()
+    {
+        data.windows.push(hwnd);
+    }
+    return BOOL(1);
+}
+
+struct FindWindowData
+{
+    title: String,
+    pid: u32,
+}
+
+unsafe extern "system" fn find_window_callback(hwnd: HWND, lparam: LPARAM) -> BOOL
+{
+    let data = &mut *(lparam.0 as *mut FindWindowData);
+
+    let mut buffer = [0u16; 512];
+    let length = GetWindowTextW(hwnd, &mut buffer);
+    if length > 0 && IsWindowVisible(hwnd).as_bool() && String::from_utf16_lossy(&buffer[..length as usize]).to_lowercase().contains(&data.title)
+    {
+        GetWindowThreadProcessId(hwnd, Some(&mut data.pid));
+        return BOOL(0);
+    }
+    return BOOL(1);
+}
+
+impl Process
+{
+    /// Returns the visible top-level windows that belong to the attached process.
+    /// Returns an empty list when not attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let windows = process.get_windows();
+    /// ```
+    pub fn get_windows(&self) -> Vec<HWND>
+    {
+        if !self.is_attached()
+        {
+            return Vec::new();
+        }
+
+        let mut data = EnumWindowsData
+        {
+            pid: self.process_data.borrow().id,
+            windows: Vec::new(),
+        };
+
+        unsafe
+        {
+            let _ = EnumWindows(Some(enum_windows_callback), LPARAM(&mut data as *mut EnumWindowsData as isize));
+        }
+        return data.windows;
+    }
+
+    /// Attaches to the process that owns the top-level window with the given title, see attach_pid.
+    /// When match_substring is true, attaches to the first visible window whose title contains the given title, ignoring case,
+    /// for titles that include a changing suffix such as the fps. Otherwise the title must match exactly.
+    /// Returns an error when no matching window is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.attach_by_window_title("DARK SOULS", true)?;
+    /// ```
+    pub fn attach_by_window_title(&mut self, title: &str, match_substring: bool) -> Result<RefreshStatus, String>
+    {
+        let mut pid = 0u32;
+        unsafe
+        {
+            if match_substring
+            {
+                let mut data = FindWindowData
+                {
+                    title: title.to_lowercase(),
+                    pid: 0,
+                };
+                let _ = EnumWindows(Some(find_window_callback), LPARAM(&mut data as *mut FindWindowData as isize));
+                pid = data.pid;
+            }
+            else
+            {
+                let wide_title: Vec<u16> = title.encode_utf16().chain(std::iter::once(0)).collect();
+                let hwnd = FindWindowW(PCWSTR::null(), PCWSTR(wide_title.as_ptr()));
+                if hwnd.0 != 0
+                {
+                    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+                }
+            }
+        }
+
+        if pid == 0
+        {
+            return Err(format!("No window found with title: {}", title));
+        }
+        return self.attach_pid(pid);
+    }
+}
@@ -14,9 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
-use windows::Win32::Foundation::HANDLE;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+use log::{info, warn};
+use windows::Win32::System::Threading::{GetExitCodeProcess, PROCESS_ACCESS_RIGHTS, PROCESS_QUERY_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE};
+use crate::process::STILL_ACTIVE;
+use crate::process_handle::ProcessHandle;
 use crate::process_module::ProcessModule;
 
+//Minimum time between enumerating running processes in refresh while not attached
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+
+//Access rights used to open the process in refresh
+pub const DEFAULT_ACCESS_RIGHTS: PROCESS_ACCESS_RIGHTS = PROCESS_ACCESS_RIGHTS(PROCESS_QUERY_INFORMATION.0 | PROCESS_VM_READ.0 | PROCESS_VM_WRITE.0 | PROCESS_VM_OPERATION.0);
+
 pub struct ProcessData
 {
     pub attached: bool,
@@ -26,10 +37,35 @@ pub struct ProcessData
     pub path: String,
 
     pub id: u32,
-    pub handle: HANDLE,
+    pub handle: ProcessHandle,
 
     pub main_module: ProcessModule,
     pub modules: Vec<ProcessModule>,
+
+    pub applied_patches: HashSet<String>,
+
+    pub from_buffer: bool,
+    pub buffer_regions: Vec<(usize, Vec<u8>)>,
+
+    pub last_pid: u32,
+
+    pub dry_run: bool,
+
+    pub is_64_bit: bool,
+
+    pub read_only: bool,
+
+    pub refresh_interval: Duration,
+    pub last_enumeration: Option<Instant>,
+
+    pub access_rights: PROCESS_ACCESS_RIGHTS,
+    pub dump_main_module: bool,
+    pub match_full_path: bool,
+    pub redump_on_refresh: bool,
+
+    pub target_pid: Option<u32>,
+    pub window_title: Option<String>,
+    pub match_window_substring: bool,
 }
 
 impl Default for ProcessData
@@ -41,11 +77,136 @@ impl Default for ProcessData
             name: String::new(),
             attached: false,
             id: 0,
-            handle: HANDLE::default(),
+            handle: ProcessHandle::default(),
             filename: String::new(),
             path: String::new(),
             main_module: ProcessModule::default(),
             modules: Vec::new(),
+            applied_patches: HashSet::new(),
+            from_buffer: false,
+            buffer_regions: Vec::new(),
+            last_pid: 0,
+            dry_run: false,
+            is_64_bit: true,
+            read_only: false,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            last_enumeration: None,
+            access_rights: DEFAULT_ACCESS_RIGHTS,
+            dump_main_module: true,
+            match_full_path: false,
+            redump_on_refresh: false,
+            target_pid: None,
+            window_title: None,
+            match_window_substring: false,
+        }
+    }
+}
+
+impl ProcessData
+{
+    /// Reset all state that belongs to an attached process
+    pub fn detach(&mut self)
+    {
+        self.attached = false;
+        self.id = 0;
+        self.handle = ProcessHandle::default();
+        self.filename = String::new();
+        self.path = String::new();
+        self.main_module = ProcessModule::default();
+        self.modules = Vec::new();
+        self.applied_patches.clear();
+        self.is_64_bit = true;
+        self.read_only = false;
+    }
+
+    /// Detach when the attached process has exited. Used after a failed read or write to keep the attached state consistent.
+    /// Returns true if the process exited.
+    pub fn detach_if_exited(&mut self) -> bool
+    {
+        if !self.attached || self.from_buffer
+        {
+            return false;
+        }
+
+        let mut lp_exit_code: u32 = 0;
+        if unsafe { GetExitCodeProcess(self.handle.raw(), &mut lp_exit_code).is_ok() } && lp_exit_code == STILL_ACTIVE
+        {
+            return false;
+        }
+        self.detach();
+        return true;
+    }
+
+    /// Read from the main module memory or the extra regions of a process that was created from a buffer
+    pub fn read_buffer(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        if buffer.is_empty()
+        {
+            return true;
+        }
+        match self.find_buffer(address, buffer.len())
+        {
+            Some((region, offset)) =>
+            {
+                let memory = if region == 0 { &self.main_module.memory } else { &self.buffer_regions[region - 1].1 };
+                buffer.copy_from_slice(&memory[offset..offset + buffer.len()]);
+                return true;
+            }
+            None => return false,
+        }
+    }
+
+    /// Write to the main module memory or the extra regions of a process that was created from a buffer
+    pub fn write_buffer(&mut self, address: usize, buffer: &[u8]) -> bool
+    {
+        if buffer.is_empty()
+        {
+            return true;
+        }
+        match self.find_buffer(address, buffer.len())
+        {
+            Some((region, offset)) =>
+            {
+                let memory = if region == 0 { &mut self.main_module.memory } else { &mut self.buffer_regions[region - 1].1 };
+                memory[offset..offset + buffer.len()].copy_from_slice(buffer);
+                return true;
+            }
+            None => return false,
+        }
+    }
+
+    //Finds the buffer that fully contains [address, address + len). Returns 0 for the main module and i + 1 for buffer region i, and the offset in that buffer
+    fn find_buffer(&self, address: usize, len: usize) -> Option<(usize, usize)>
+    {
+        let end = address.checked_add(len)?;
+        let main = (self.main_module.base_address, &self.main_module.memory);
+        for (i, (base, memory)) in std::iter::once(main).chain(self.buffer_regions.iter().map(|(base, memory)| (*base, memory))).enumerate()
+        {
+            if address >= base && base.checked_add(memory.len()).is_some_and(|region_end| end <= region_end)
+            {
+                return Some((i, address - base));
+            }
+        }
+        return None;
+    }
+
+    /// When dry run is enabled, logs a write instead of performing it and returns true
+    pub fn dry_run_write(&self, address: usize, buffer: &[u8]) -> bool
+    {
+        if self.dry_run
+        {
+            info!("Dry run write to {:#x}: {:02x?}", address, buffer);
+        }
+        return self.dry_run;
+    }
+
+    /// When attached read-only, logs that a write was rejected and returns true
+    pub fn reject_read_only_write(&self, address: usize) -> bool
+    {
+        if self.read_only
+        {
+            warn!("Write to {:#x} failed: read-only attachment", address);
         }
+        return self.read_only;
     }
 }
\ No newline at end of file
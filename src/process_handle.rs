@@ -0,0 +1,78 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::Foundation::{BOOL, CloseHandle, DUPLICATE_SAME_ACCESS, DuplicateHandle, HANDLE};
+use windows::Win32::System::Threading::GetCurrentProcess;
+
+/// Owns a native process handle. The handle is closed when it is replaced or dropped,
+/// for example when the process is detached.
+#[derive(Default)]
+pub struct ProcessHandle
+{
+    handle: HANDLE,
+}
+
+impl ProcessHandle
+{
+    pub(crate) fn new(handle: HANDLE) -> Self
+    {
+        ProcessHandle { handle }
+    }
+
+    /// Returns if this handle refers to an open process
+    pub fn is_valid(&self) -> bool
+    {
+        return !self.handle.is_invalid();
+    }
+
+    /// Duplicates the handle into a new ProcessHandle with the same access rights, that stays open after this one is closed.
+    /// Returns None when this handle is not valid or can't be duplicated.
+    pub fn try_clone(&self) -> Option<ProcessHandle>
+    {
+        if !self.is_valid()
+        {
+            return None;
+        }
+
+        let mut handle = HANDLE::default();
+        unsafe
+        {
+            let current_process = GetCurrentProcess();
+            if DuplicateHandle(current_process, self.handle, current_process, &mut handle, 0, BOOL(0), DUPLICATE_SAME_ACCESS).is_err()
+            {
+                return None;
+            }
+        }
+        return Some(ProcessHandle::new(handle));
+    }
+
+    //The raw handle must not be used after this ProcessHandle is closed
+    pub(crate) fn raw(&self) -> HANDLE
+    {
+        return self.handle;
+    }
+}
+
+impl Drop for ProcessHandle
+{
+    fn drop(&mut self)
+    {
+        if self.is_valid()
+        {
+            unsafe { let _ = CloseHandle(self.handle); }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use windows::Win32::System::ProcessStatus::PROCESS_MEMORY_COUNTERS;
+
+/// Memory usage statistics of a process, as returned by GetProcessMemoryInfo. Sizes are in bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct ProcessMemoryInfo
+{
+    pub page_fault_count: u32,
+    pub working_set_size: usize,
+    pub peak_working_set_size: usize,
+    pub pagefile_usage: usize,
+    pub peak_pagefile_usage: usize,
+}
+
+impl From<PROCESS_MEMORY_COUNTERS> for ProcessMemoryInfo
+{
+    fn from(counters: PROCESS_MEMORY_COUNTERS) -> Self
+    {
+        ProcessMemoryInfo
+        {
+            page_fault_count: counters.PageFaultCount,
+            working_set_size: counters.WorkingSetSize,
+            peak_working_set_size: counters.PeakWorkingSetSize,
+            pagefile_usage: counters.PagefileUsage,
+            peak_pagefile_usage: counters.PeakPagefileUsage,
+        }
+    }
+}
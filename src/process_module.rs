@@ -15,9 +15,13 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use std::ffi::c_void;
+use std::fs::File;
+use std::io::Read;
 use windows::Win32::Foundation::{HANDLE};
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessModule
 {
     pub id: usize,
@@ -27,6 +31,10 @@ pub struct ProcessModule
     pub base_address: usize,
     pub size: usize,
 
+    /// The rva at which the dumped memory starts, 0 unless only a range of the module was dumped
+    pub dump_rva: usize,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub memory: Vec<u8>,
 }
 
@@ -40,6 +48,7 @@ impl Default for ProcessModule
             name: String::new(),
             base_address: 0,
             size: 0,
+            dump_rva: 0,
             memory: Vec::new(),
         }
     }
@@ -49,7 +58,7 @@ impl ProcessModule
 {
     pub fn new(id: usize, path: String, name: String, base: usize, size: usize) -> Self
     {
-        ProcessModule { id, path, name, base_address: base, size, memory: Vec::new() }
+        ProcessModule { id, path, name, base_address: base, size, dump_rva: 0, memory: Vec::new() }
     }
 
     pub fn dump_memory(&mut self, process_handle: HANDLE)
@@ -64,6 +73,241 @@ impl ProcessModule
                 return;
             }
             self.memory = buffer;
+            self.dump_rva = 0;
+        }
+    }
+
+    /// Dumps only the range [base_address + rva, base_address + rva + len) of the module, for example a single section.
+    /// Scans on the module only search the dumped range. Leaves the dump unchanged when the range is outside the module or the read fails.
+    /// Returns if the range was dumped.
+    pub fn dump_range(&mut self, process_handle: HANDLE, rva: usize, len: usize) -> bool
+    {
+        if rva.checked_add(len).is_none_or(|end| end > self.size)
+        {
+            return false;
+        }
+
+        unsafe
+        {
+            let mut buffer: Vec<u8> = vec![0; len];
+            let mut read_bytes = 0;
+
+            if ReadProcessMemory(process_handle, (self.base_address + rva) as *mut c_void, buffer.as_mut_ptr() as *mut c_void, buffer.len(), Some(&mut read_bytes)).is_err()
+            {
+                return false;
+            }
+            self.memory = buffer;
+            self.dump_rva = rva;
+            return true;
+        }
+    }
+
+    /// Returns the absolute address of the first byte of the dumped memory
+    pub fn get_dump_address(&self) -> usize
+    {
+        return self.base_address + self.dump_rva;
+    }
+
+    /// Read a relative virtual address (u32) from the dumped memory at the given offset and convert it
+    /// to an absolute address by adding the module's base address. Returns 0 if the offset is outside the dump.
+    pub fn read_rva_rel(&self, offset: usize) -> usize
+    {
+        if offset < self.dump_rva || offset - self.dump_rva + 4 > self.memory.len()
+        {
+            return 0;
         }
+        let offset = offset - self.dump_rva;
+
+        let rva = u32::from_le_bytes(self.memory[offset..offset + 4].try_into().unwrap());
+        return self.base_address + rva as usize;
     }
-}
\ No newline at end of file
+
+    /// Returns a 64 bit FNV-1a hash of the dumped memory. Can be used to fingerprint the version of a module.
+    pub fn hash(&self) -> u64
+    {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in &self.memory
+        {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        return hash;
+    }
+
+    /// Returns the preferred image base from the PE optional header, or 0 if the headers can't be parsed.
+    /// The loader overwrites the image base in the loaded headers when a module is relocated, so the headers are read
+    /// from the file at path when possible, falling back to the dumped memory.
+    /// Comparing it to base_address reveals whether the module was relocated.
+    pub fn get_image_base(&self) -> usize
+    {
+        if let Ok(file) = File::open(&self.path)
+        {
+            let mut headers = Vec::new();
+            if file.take(0x1000).read_to_end(&mut headers).is_ok()
+            {
+                if let Some(image_base) = read_image_base(&headers)
+                {
+                    return image_base;
+                }
+            }
+        }
+        if self.dump_rva != 0
+        {
+            return 0;
+        }
+        return read_image_base(&self.memory).unwrap_or(0);
+    }
+
+    /// Applies the base relocations of the dumped module as if it was loaded at new_base instead of base_address,
+    /// and updates base_address. Uses the base relocation table (data directory 5) in the dump.
+    /// Makes dumps taken at different ASLR bases comparable.
+    pub fn rebase_dump(&mut self, new_base: usize) -> Result<(), String>
+    {
+        let (relocation_rva, relocation_size, _) = self.get_data_directory(5)?;
+        let memory = &self.memory;
+        let read_u32 = |offset: usize| memory.get(offset..offset + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize);
+
+        //Collect the fixups first, since the memory is borrowed by read_u32
+        let mut fixups = Vec::new();
+        let mut block = relocation_rva;
+        while block + 8 <= relocation_rva + relocation_size
+        {
+            let page_rva = read_u32(block).ok_or("Invalid relocation block")?;
+            let block_size = read_u32(block + 4).ok_or("Invalid relocation block")?;
+            if block_size < 8
+            {
+                break;
+            }
+
+            for entry_offset in (block + 8..block + block_size).step_by(2)
+            {
+                let entry = u16::from_le_bytes(memory.get(entry_offset..entry_offset + 2).ok_or("Invalid relocation entry")?.try_into().unwrap());
+                let kind = entry >> 12;
+                let offset = page_rva + (entry & 0xfff) as usize;
+                match kind
+                {
+                    3 | 10 => fixups.push((kind, offset)),
+                    _ => {}, //IMAGE_REL_BASED_ABSOLUTE is padding, other types are not used on x86/x64
+                }
+            }
+            block += block_size;
+        }
+
+        let delta = new_base.wrapping_sub(self.base_address);
+        for (kind, offset) in fixups
+        {
+            if kind == 3
+            {
+                //IMAGE_REL_BASED_HIGHLOW
+                if let Some(bytes) = self.memory.get_mut(offset..offset + 4)
+                {
+                    let value = u32::from_le_bytes(bytes.try_into().unwrap()).wrapping_add(delta as u32);
+                    bytes.copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            else
+            {
+                //IMAGE_REL_BASED_DIR64
+                if let Some(bytes) = self.memory.get_mut(offset..offset + 8)
+                {
+                    let value = u64::from_le_bytes(bytes.try_into().unwrap()).wrapping_add(delta as u64);
+                    bytes.copy_from_slice(&value.to_le_bytes());
+                }
+            }
+        }
+
+        self.base_address = new_base;
+        return Ok(());
+    }
+
+    /// Returns the absolute addresses of the TLS callbacks of the module, which run before the entry point.
+    /// Reads the TLS directory (data directory 9) from the dump. Returns an empty vec when the module has no TLS callbacks.
+    pub fn get_tls_callbacks(&self) -> Vec<usize>
+    {
+        let mut result = Vec::new();
+        let (tls_rva, tls_size, is_64_bit) = match self.get_data_directory(9)
+        {
+            Ok(directory) => directory,
+            Err(_) => return result,
+        };
+        if tls_rva == 0 || tls_size == 0
+        {
+            return result;
+        }
+
+        //AddressOfCallBacks and the callbacks are virtual addresses, already relocated by the loader
+        let pointer_size = if is_64_bit { 8 } else { 4 };
+        let read_pointer = |offset: usize| self.memory.get(offset..offset + pointer_size).map(|b|
+        {
+            if is_64_bit { u64::from_le_bytes(b.try_into().unwrap()) as usize } else { u32::from_le_bytes(b.try_into().unwrap()) as usize }
+        });
+
+        let callbacks = match read_pointer(tls_rva + pointer_size * 3)
+        {
+            Some(address) if address >= self.base_address => address - self.base_address,
+            _ => return result,
+        };
+
+        let mut offset = callbacks;
+        while let Some(callback) = read_pointer(offset)
+        {
+            if callback == 0
+            {
+                break;
+            }
+            result.push(callback);
+            offset += pointer_size;
+        }
+        return result;
+    }
+
+    //Returns the rva and size of a data directory from the dumped headers, and if the module is PE32+
+    fn get_data_directory(&self, index: usize) -> Result<(usize, usize, bool), String>
+    {
+        if self.dump_rva != 0
+        {
+            return Err(String::from("Dump does not contain the headers"));
+        }
+
+        let memory = &self.memory;
+        let read_u32 = |offset: usize| memory.get(offset..offset + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize);
+
+        let e_lfanew = read_u32(0x3c).ok_or("Invalid dos header")?;
+        if memory.get(e_lfanew..e_lfanew + 4) != Some(b"PE\0\0")
+        {
+            return Err(String::from("Invalid nt headers"));
+        }
+
+        //Data directories follow the fixed part of the optional header, which is larger for PE32+
+        let optional_header = e_lfanew + 24;
+        let (data_directories, is_64_bit) = match memory.get(optional_header..optional_header + 2)
+        {
+            Some([0x0b, 0x01]) => (optional_header + 96, false),
+            Some([0x0b, 0x02]) => (optional_header + 112, true),
+            _ => return Err(String::from("Invalid optional header")),
+        };
+
+        let rva = read_u32(data_directories + index * 8).ok_or("Invalid data directory")?;
+        let size = read_u32(data_directories + index * 8 + 4).ok_or("Invalid data directory")?;
+        return Ok((rva, size, is_64_bit));
+    }
+}
+
+fn read_image_base(headers: &[u8]) -> Option<usize>
+{
+    let e_lfanew = u32::from_le_bytes(headers.get(0x3c..0x40)?.try_into().unwrap()) as usize;
+    if headers.get(e_lfanew..e_lfanew + 4)? != b"PE\0\0"
+    {
+        return None;
+    }
+
+    //The optional header follows the 4 byte signature and the 20 byte file header
+    let optional_header = e_lfanew + 24;
+    let magic = u16::from_le_bytes(headers.get(optional_header..optional_header + 2)?.try_into().unwrap());
+    match magic
+    {
+        0x10b => return Some(u32::from_le_bytes(headers.get(optional_header + 28..optional_header + 32)?.try_into().unwrap()) as usize),
+        0x20b => return Some(u64::from_le_bytes(headers.get(optional_header + 24..optional_header + 32)?.try_into().unwrap()) as usize),
+        _ => return None,
+    }
+}
@@ -15,9 +15,42 @@
 // along with this program. If not, see <http://www.gnu.org/licenses/>.
 
 use std::ffi::c_void;
+use std::ops::RangeInclusive;
 use windows::Win32::Foundation::HANDLE;
 use windows::Win32::System::Diagnostics::Debug::{ ReadProcessMemory, WriteProcessMemory};
 
+/// Marker for types that can be read from and written to memory as raw bytes. Every api that copies raw bytes into or out of a value is bound on it:
+/// ReadWrite::read, write, try_read_struct_rel, read_into_rel and write_struct_rel, Pointer::read_tarray_rel, Process::write_value_abs and TrackedValue.
+/// bool, char and enums are not Pod because not every bit pattern is valid for them, read them as u8 or u32 instead.
+///
+/// # Safety
+///
+/// Implementors must be Copy, #[repr(C)] (or a primitive), contain no padding bytes, pointers or references,
+/// and every bit pattern of size_of::<T>() bytes must be a valid value of the type.
+/// Alignment is not required, values are always copied with unaligned reads.
+///
+/// # Example
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Position { x: f32, y: f32, z: f32 }
+///
+/// unsafe impl Pod for Position {}
+/// ```
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod
+{
+    ($($t:ty),*) => { $(unsafe impl Pod for $t {})* };
+}
+
+impl_pod!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
 pub trait BaseReadWrite
 {
     /// Read memory relative to the object's location in memory. Supports an optional offset.
@@ -87,6 +120,12 @@ pub trait BaseReadWrite
     /// Read memory into a buffer from a process handle
     fn read_with_handle(&self, handle: HANDLE, address: usize, buffer: &mut [u8]) -> bool
     {
+        //Nothing to read, ReadProcessMemory doesn't handle empty buffers consistently
+        if buffer.is_empty()
+        {
+            return true;
+        }
+
         let mut read_bytes = 0;
         if unsafe {ReadProcessMemory(handle, address as *mut c_void, buffer.as_mut_ptr() as *mut c_void, buffer.len(), Some(&mut read_bytes)).is_err() }
         {
@@ -98,6 +137,11 @@ pub trait BaseReadWrite
     /// Write from a buffer ino memory from a process handle
     fn write_with_handle(&self, handle: HANDLE, address: usize, buffer: &[u8]) -> bool
     {
+        if buffer.is_empty()
+        {
+            return true;
+        }
+
         let mut wrote_bytes = 0;
         if unsafe { WriteProcessMemory(handle, address as *mut c_void, buffer.as_ptr() as *mut c_void, buffer.len(), Some(&mut wrote_bytes)).is_err() }
         {
@@ -129,7 +173,27 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 1];
         self.read_memory_rel(address, &mut buffer);
-        return i8::from_ne_bytes(buffer);
+        return i8::from_le_bytes(buffer);
+    }
+
+    /// Relatively read an i16 from an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data = pointer.read_i16_rel(Some(0x1234));
+    /// ```
+    fn read_i16_rel(&self, address: Option<usize>) -> i16
+    {
+        let mut buffer = [0; 2];
+        self.read_memory_rel(address, &mut buffer);
+        return i16::from_le_bytes(buffer);
     }
 
     /// Relatively read an i32 from an optional offset
@@ -149,7 +213,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 4];
         self.read_memory_rel(address, &mut buffer);
-        return i32::from_ne_bytes(buffer);
+        return i32::from_le_bytes(buffer);
     }
 
     /// Relatively read an i64 from an optional offset
@@ -169,7 +233,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 8];
         self.read_memory_rel(address, &mut buffer);
-        return i64::from_ne_bytes(buffer);
+        return i64::from_le_bytes(buffer);
     }
 
     /// Relatively read an u8 from an optional offset
@@ -192,6 +256,26 @@ pub trait ReadWrite: BaseReadWrite
         return buffer[0];
     }
 
+    /// Relatively read an u16 from an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data = pointer.read_u16_rel(Some(0x1234));
+    /// ```
+    fn read_u16_rel(&self, address: Option<usize>) -> u16
+    {
+        let mut buffer = [0; 2];
+        self.read_memory_rel(address, &mut buffer);
+        return u16::from_le_bytes(buffer);
+    }
+
     /// Relatively read an u32 from an optional offset
     ///
     /// # Example
@@ -209,7 +293,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 4];
         self.read_memory_rel(address, &mut buffer);
-        return u32::from_ne_bytes(buffer);
+        return u32::from_le_bytes(buffer);
     }
 
     /// Relatively read an u64 from an optional offset
@@ -229,7 +313,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 8];
         self.read_memory_rel(address, &mut buffer);
-        return u64::from_ne_bytes(buffer);
+        return u64::from_le_bytes(buffer);
     }
 
     /// Relatively read an f32 from an optional offset
@@ -249,7 +333,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 4];
         self.read_memory_rel(address, &mut buffer);
-        return f32::from_ne_bytes(buffer);
+        return f32::from_le_bytes(buffer);
     }
 
     /// Relatively read an f64 from an optional offset
@@ -269,7 +353,7 @@ pub trait ReadWrite: BaseReadWrite
     {
         let mut buffer = [0; 8];
         self.read_memory_rel(address, &mut buffer);
-        return f64::from_ne_bytes(buffer);
+        return f64::from_le_bytes(buffer);
     }
 
     /// Relatively read a bool from an optional offset.
@@ -293,10 +377,29 @@ pub trait ReadWrite: BaseReadWrite
         return buffer[0] != 0;
     }
 
-    //==================================================================================================================================================================
-    //Writing
+    /// Relatively read a windows BOOL from an optional offset.
+    /// 4 bytes are read from the resolved address, the value is true if any of these bytes is non-zero. Use read_bool_rel for 1 byte BOOLEAN's.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data = pointer.read_win_bool_rel(Some(0x1234));
+    /// ```
+    fn read_win_bool_rel(&self, address: Option<usize>) -> bool
+    {
+        let mut buffer = [0; 4];
+        self.read_memory_rel(address, &mut buffer);
+        return i32::from_le_bytes(buffer) != 0;
+    }
 
-    /// Relatively write an i8 to an optional offset
+    /// Relatively read a fixed-point value from an optional offset.
+    /// An i32 is read from the resolved address and divided by scale, e.g. a scale of 100.0 for health stored as health * 100.
     ///
     /// # Example
     ///
@@ -307,16 +410,14 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: i8 = 10;
-    /// pointer.write_i8_rel(Some(0x1234), data);
+    /// let data = pointer.read_fixed_rel(Some(0x1234), 100.0);
     /// ```
-    fn write_i8_rel(&self, address: Option<usize>, value: i8)
+    fn read_fixed_rel(&self, address: Option<usize>, scale: f64) -> f64
     {
-        let buffer = value.to_ne_bytes();
-        self.write_memory_rel(address, &buffer);
+        return self.read_i32_rel(address) as f64 / scale;
     }
 
-    /// Relatively write an i32 to an optional offset
+    /// Relatively read 3 contiguous f32's from an optional offset in a single read, such as a position.
     ///
     /// # Example
     ///
@@ -327,16 +428,16 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: i32 = 10;
-    /// pointer.write_i32_rel(Some(0x1234), data);
+    /// let [x, y, z] = pointer.read_vec3_rel(Some(0x1234));
     /// ```
-    fn write_i32_rel(&self, address: Option<usize>, value: i32)
+    fn read_vec3_rel(&self, address: Option<usize>) -> [f32; 3]
     {
-        let buffer = value.to_ne_bytes();
-        self.write_memory_rel(address, &buffer);
+        let mut buffer = [0; 12];
+        self.read_memory_rel(address, &mut buffer);
+        return std::array::from_fn(|i| f32::from_le_bytes(buffer[i * 4..i * 4 + 4].try_into().unwrap()));
     }
 
-    /// Relatively write an i64 to an optional offset
+    /// Relatively read 4 contiguous f32's from an optional offset in a single read, such as a rotation quaternion.
     ///
     /// # Example
     ///
@@ -347,16 +448,17 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: i64 = 10;
-    /// pointer.write_i64_rel(Some(0x1234), data);
+    /// let [x, y, z, w] = pointer.read_vec4_rel(Some(0x1234));
     /// ```
-    fn write_i64_rel(&self, address: Option<usize>, value: i64)
+    fn read_vec4_rel(&self, address: Option<usize>) -> [f32; 4]
     {
-        let buffer = value.to_ne_bytes();
-        self.write_memory_rel(address, &buffer);
+        let mut buffer = [0; 16];
+        self.read_memory_rel(address, &mut buffer);
+        return std::array::from_fn(|i| f32::from_le_bytes(buffer[i * 4..i * 4 + 4].try_into().unwrap()));
     }
 
-    /// Relatively write an u8 to an optional offset
+    /// Relatively read a u32 from an optional offset and check that it lies within an expected range.
+    /// Returns an error when the read fails or the value is outside the range, which usually means an offset or signature broke.
     ///
     /// # Example
     ///
@@ -367,16 +469,220 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: u8 = 10;
-    /// pointer.write_u8_rel(Some(0x1234), data);
+    /// let health = pointer.read_u32_expect_rel(Some(0x3e8), 0..=99999)?;
     /// ```
-    fn write_u8_rel(&self, address: Option<usize>, value: u8)
+    fn read_u32_expect_rel(&self, address: Option<usize>, range: RangeInclusive<u32>) -> Result<u32, String>
+    {
+        let mut buffer = [0; 4];
+        if !self.read_memory_rel(address, &mut buffer)
+        {
+            return Err(String::from("Failed to read u32"));
+        }
+
+        let value = u32::from_le_bytes(buffer);
+        if !range.contains(&value)
+        {
+            return Err(format!("Value {} outside of expected range {:?}", value, range));
+        }
+        return Ok(value);
+    }
+
+    /// Relatively read a plain-old-data type from an optional offset.
+    /// Returns None when the read fails. Requires the bytemuck feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+    /// struct Position { x: f32, y: f32, z: f32 }
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: Option<Position> = pointer.read_pod_rel(Some(0x1234));
+    /// ```
+    #[cfg(feature = "bytemuck")]
+    fn read_pod_rel<T: bytemuck::Pod>(&self, address: Option<usize>) -> Option<T>
+    {
+        let mut buffer = vec![0u8; std::mem::size_of::<T>()];
+        if !self.read_memory_rel(address, &mut buffer)
+        {
+            return None;
+        }
+        return bytemuck::try_pod_read_unaligned(&buffer).ok();
+    }
+
+    /// Relatively read a struct from an optional offset. Returns None unless all size_of::<T>() bytes were read.
+    /// The bytes are copied into T as-is, see Pod for the requirements on T.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy)]
+    /// struct Position { x: f32, y: f32, z: f32 }
+    ///
+    /// unsafe impl Pod for Position {}
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: Option<Position> = pointer.try_read_struct_rel(Some(0x1234));
+    /// ```
+    fn try_read_struct_rel<T: Pod>(&self, address: Option<usize>) -> Option<T>
+    {
+        let mut buffer = vec![0u8; std::mem::size_of::<T>()];
+        if !self.read_memory_rel(address, &mut buffer)
+        {
+            return None;
+        }
+        return Some(unsafe { std::ptr::read_unaligned(buffer.as_ptr() as *const T) });
+    }
+
+    /// Relatively read a struct from an optional offset directly over the bytes of an existing value, to reuse it across reads.
+    /// Returns if all size_of::<T>() bytes were read. See Pod for the requirements on T.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Default)]
+    /// struct Position { x: f32, y: f32, z: f32 }
+    ///
+    /// unsafe impl Pod for Position {}
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let mut position = Position::default();
+    /// let success = pointer.read_into_rel(Some(0x1234), &mut position);
+    /// ```
+    fn read_into_rel<T: Pod>(&self, address: Option<usize>, out: &mut T) -> bool
+    {
+        let buffer = unsafe { std::slice::from_raw_parts_mut(out as *mut T as *mut u8, std::mem::size_of::<T>()) };
+        return self.read_memory_rel(address, buffer);
+    }
+
+    /// Relatively read any Pod type from an optional offset. Reads size_of::<T>() bytes and copies them into T with an unaligned read,
+    /// the address does not have to be aligned. When the read fails the value is built from zeroed bytes, which is valid for any Pod type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let hp: u32 = pointer.read(Some(0x10));
+    /// ```
+    fn read<T: Pod>(&self, address: Option<usize>) -> T
+    {
+        let mut buffer = vec![0u8; std::mem::size_of::<T>()];
+        self.read_memory_rel(address, &mut buffer);
+        return unsafe { std::ptr::read_unaligned(buffer.as_ptr() as *const T) };
+    }
+
+    /// Relatively read len bytes from an optional offset into an owned buffer. Returns None when the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data = pointer.read_bytes_rel(Some(0x1234), 16);
+    /// ```
+    fn read_bytes_rel(&self, address: Option<usize>, len: usize) -> Option<Vec<u8>>
+    {
+        let mut buffer = vec![0u8; len];
+        if !self.read_memory_rel(address, &mut buffer)
+        {
+            return None;
+        }
+        return Some(buffer);
+    }
+
+    /// Relatively read a null terminated string from an optional offset. Reading stops at the first null terminator or after max_len bytes, whichever comes first.
+    /// The read grows in chunks, falling back to single bytes when a chunk crosses into unreadable memory. Whatever was read before that point is returned,
+    /// so an unreadable address results in an empty string. Invalid utf-8 is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let name = pointer.read_string_rel(Some(0x1234), 64);
+    /// ```
+    fn read_string_rel(&self, address: Option<usize>, max_len: usize) -> String
+    {
+        let bytes = read_null_terminated(|buffer| self.read_memory_rel(address, buffer), max_len, 1);
+        return String::from_utf8_lossy(&bytes).into_owned();
+    }
+
+    /// Relatively read a null terminated utf-16 string (wchar_t*) from an optional offset. Reading stops at the first null u16 or after max_chars code units, whichever comes first.
+    /// Like read_string_rel, whatever was read before unreadable memory is returned, so an unreadable address results in an empty string. Invalid utf-16 is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let name = pointer.read_utf16_string_rel(Some(0x1234), 64);
+    /// ```
+    fn read_utf16_string_rel(&self, address: Option<usize>, max_chars: usize) -> String
+    {
+        let bytes = read_null_terminated(|buffer| self.read_memory_rel(address, buffer), max_chars, 2);
+        let chars: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        return String::from_utf16_lossy(&chars);
+    }
+
+    //==================================================================================================================================================================
+    //Writing
+
+    /// Relatively write an i8 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: i8 = 10;
+    /// pointer.write_i8_rel(Some(0x1234), data);
+    /// ```
+    fn write_i8_rel(&self, address: Option<usize>, value: i8)
     {
-        let buffer = value.to_ne_bytes();
+        let buffer = value.to_le_bytes();
         self.write_memory_rel(address, &buffer);
     }
 
-    /// Relatively write an u32 to an optional offset
+    /// Relatively write an i16 to an optional offset
     ///
     /// # Example
     ///
@@ -387,16 +693,16 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: u32 = 10;
-    /// pointer.write_u32_rel(Some(0x1234), data);
+    /// let data: i16 = 10;
+    /// pointer.write_i16_rel(Some(0x1234), data);
     /// ```
-    fn write_u32_rel(&self, address: Option<usize>, value: u32)
+    fn write_i16_rel(&self, address: Option<usize>, value: i16)
     {
-        let buffer = value.to_ne_bytes();
+        let buffer = value.to_le_bytes();
         self.write_memory_rel(address, &buffer);
     }
 
-    /// Relatively write an u64 to an optional offset
+    /// Relatively write an i32 to an optional offset
     ///
     /// # Example
     ///
@@ -407,16 +713,16 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: u64 = 10;
-    /// pointer.write_u64_rel(Some(0x1234), data);
+    /// let data: i32 = 10;
+    /// pointer.write_i32_rel(Some(0x1234), data);
     /// ```
-    fn write_u64_rel(&self, address: Option<usize>, value: u64)
+    fn write_i32_rel(&self, address: Option<usize>, value: i32)
     {
-        let buffer = value.to_ne_bytes();
+        let buffer = value.to_le_bytes();
         self.write_memory_rel(address, &buffer);
     }
 
-    /// Relatively write an f32 to an optional offset
+    /// Relatively write an i64 to an optional offset
     ///
     /// # Example
     ///
@@ -427,16 +733,16 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: f32 = 10.0f32;
-    /// pointer.write_f32_rel(Some(0x1234), data);
+    /// let data: i64 = 10;
+    /// pointer.write_i64_rel(Some(0x1234), data);
     /// ```
-    fn write_f32_rel(&self, address: Option<usize>, value: f32)
+    fn write_i64_rel(&self, address: Option<usize>, value: i64)
     {
-        let buffer = value.to_ne_bytes();
+        let buffer = value.to_le_bytes();
         self.write_memory_rel(address, &buffer);
     }
 
-    /// Relatively write an f64 to an optional offset
+    /// Relatively write an u8 to an optional offset
     ///
     /// # Example
     ///
@@ -447,12 +753,811 @@ pub trait ReadWrite: BaseReadWrite
     /// process.refresh()?;
     /// let pointer = process.create_pointer(0x1234, vec![0]);
     ///
-    /// let data: f64 = 10.0f64;
-    /// pointer.write_f64_rel(Some(0x1234), data);
+    /// let data: u8 = 10;
+    /// pointer.write_u8_rel(Some(0x1234), data);
     /// ```
-    fn write_f64_rel(&self, address: Option<usize>, value: f64)
+    fn write_u8_rel(&self, address: Option<usize>, value: u8)
     {
-        let buffer = value.to_ne_bytes();
+        let buffer = value.to_le_bytes();
         self.write_memory_rel(address, &buffer);
     }
-}
\ No newline at end of file
+
+    /// Relatively write an u16 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: u16 = 10;
+    /// pointer.write_u16_rel(Some(0x1234), data);
+    /// ```
+    fn write_u16_rel(&self, address: Option<usize>, value: u16)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write an u32 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: u32 = 10;
+    /// pointer.write_u32_rel(Some(0x1234), data);
+    /// ```
+    fn write_u32_rel(&self, address: Option<usize>, value: u32)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write an u64 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: u64 = 10;
+    /// pointer.write_u64_rel(Some(0x1234), data);
+    /// ```
+    fn write_u64_rel(&self, address: Option<usize>, value: u64)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write an f32 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: f32 = 10.0f32;
+    /// pointer.write_f32_rel(Some(0x1234), data);
+    /// ```
+    fn write_f32_rel(&self, address: Option<usize>, value: f32)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write an f64 to an optional offset
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data: f64 = 10.0f64;
+    /// pointer.write_f64_rel(Some(0x1234), data);
+    /// ```
+    fn write_f64_rel(&self, address: Option<usize>, value: f64)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write a bool to an optional offset as a single byte, 1 for true and 0 for false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// pointer.write_bool_rel(Some(0x1234), true);
+    /// ```
+    fn write_bool_rel(&self, address: Option<usize>, value: bool)
+    {
+        let buffer = [value as u8];
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write a windows BOOL to an optional offset as 4 bytes, 1 for true and 0 for false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// pointer.write_win_bool_rel(Some(0x1234), true);
+    /// ```
+    fn write_win_bool_rel(&self, address: Option<usize>, value: bool)
+    {
+        let buffer = (value as i32).to_le_bytes();
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write a fixed-point value to an optional offset.
+    /// The value is multiplied by scale, rounded and written as an i32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// pointer.write_fixed_rel(Some(0x1234), 12.5, 100.0);
+    /// ```
+    fn write_fixed_rel(&self, address: Option<usize>, value: f64, scale: f64)
+    {
+        self.write_i32_rel(address, (value * scale).round() as i32);
+    }
+
+    /// Relatively write 3 contiguous f32's to an optional offset in a single write, such as a position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// pointer.write_vec3_rel(Some(0x1234), [1.0, 2.0, 3.0]);
+    /// ```
+    fn write_vec3_rel(&self, address: Option<usize>, value: [f32; 3])
+    {
+        let mut buffer = [0; 12];
+        for i in 0..3
+        {
+            buffer[i * 4..i * 4 + 4].copy_from_slice(&value[i].to_le_bytes());
+        }
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write 4 contiguous f32's to an optional offset in a single write, such as a rotation quaternion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// pointer.write_vec4_rel(Some(0x1234), [0.0, 0.0, 0.0, 1.0]);
+    /// ```
+    fn write_vec4_rel(&self, address: Option<usize>, value: [f32; 4])
+    {
+        let mut buffer = [0; 16];
+        for i in 0..4
+        {
+            buffer[i * 4..i * 4 + 4].copy_from_slice(&value[i].to_le_bytes());
+        }
+        self.write_memory_rel(address, &buffer);
+    }
+
+    /// Relatively write a struct to an optional offset in a single write. Returns if the write succeeded.
+    /// The bytes of T are copied as-is, see Pod for the requirements on T.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy)]
+    /// struct Position { x: f32, y: f32, z: f32 }
+    ///
+    /// unsafe impl Pod for Position {}
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let data = Position { x: 1.0, y: 2.0, z: 3.0 };
+    /// let success = pointer.write_struct_rel(Some(0x1234), &data);
+    /// ```
+    fn write_struct_rel<T: Pod>(&self, address: Option<usize>, value: &T) -> bool
+    {
+        let buffer = unsafe { std::slice::from_raw_parts(value as *const T as *const u8, std::mem::size_of::<T>()) };
+        return self.write_memory_rel(address, buffer);
+    }
+
+    /// Relatively write any Pod type to an optional offset as size_of::<T>() raw bytes, in a single write. The address does not have to be aligned.
+    /// Returns if the write succeeded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let pointer = process.create_pointer(0x1234, vec![0]);
+    ///
+    /// let success = pointer.write(Some(0x10), 100u32);
+    /// ```
+    fn write<T: Pod>(&self, address: Option<usize>, value: T) -> bool
+    {
+        let buffer = unsafe { std::slice::from_raw_parts(&value as *const T as *const u8, std::mem::size_of::<T>()) };
+        return self.write_memory_rel(address, buffer);
+    }
+
+    //==================================================================================================================================================================
+    //Absolute reading
+
+    /// Absolutely read an u8 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_u8_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_u8_abs(&self, address: usize) -> u8
+    {
+        let mut buffer = [0; 1];
+        self.read_memory_abs(address, &mut buffer);
+        return u8::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an i8 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_i8_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_i8_abs(&self, address: usize) -> i8
+    {
+        let mut buffer = [0; 1];
+        self.read_memory_abs(address, &mut buffer);
+        return i8::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an u16 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_u16_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_u16_abs(&self, address: usize) -> u16
+    {
+        let mut buffer = [0; 2];
+        self.read_memory_abs(address, &mut buffer);
+        return u16::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an i16 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_i16_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_i16_abs(&self, address: usize) -> i16
+    {
+        let mut buffer = [0; 2];
+        self.read_memory_abs(address, &mut buffer);
+        return i16::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an u32 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_u32_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_u32_abs(&self, address: usize) -> u32
+    {
+        let mut buffer = [0; 4];
+        self.read_memory_abs(address, &mut buffer);
+        return u32::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an i32 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_i32_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_i32_abs(&self, address: usize) -> i32
+    {
+        let mut buffer = [0; 4];
+        self.read_memory_abs(address, &mut buffer);
+        return i32::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an u64 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_u64_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_u64_abs(&self, address: usize) -> u64
+    {
+        let mut buffer = [0; 8];
+        self.read_memory_abs(address, &mut buffer);
+        return u64::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an i64 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_i64_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_i64_abs(&self, address: usize) -> i64
+    {
+        let mut buffer = [0; 8];
+        self.read_memory_abs(address, &mut buffer);
+        return i64::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an f32 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_f32_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_f32_abs(&self, address: usize) -> f32
+    {
+        let mut buffer = [0; 4];
+        self.read_memory_abs(address, &mut buffer);
+        return f32::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read an f64 from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_f64_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_f64_abs(&self, address: usize) -> f64
+    {
+        let mut buffer = [0; 8];
+        self.read_memory_abs(address, &mut buffer);
+        return f64::from_le_bytes(buffer);
+    }
+
+    /// Absolutely read a bool from an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data = process.read_bool_abs(0x7ff6a1b2c3d4);
+    /// ```
+    fn read_bool_abs(&self, address: usize) -> bool
+    {
+        let mut buffer = [0; 1];
+        self.read_memory_abs(address, &mut buffer);
+        return buffer[0] != 0;
+    }
+
+    /// Absolutely read len bytes from an address into an owned buffer. Returns None when the read fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    ///
+    /// let data = process.read_bytes_abs(0x7ff6a1b2c3d4, 16);
+    /// ```
+    fn read_bytes_abs(&self, address: usize, len: usize) -> Option<Vec<u8>>
+    {
+        let mut buffer = vec![0u8; len];
+        if !self.read_memory_abs(address, &mut buffer)
+        {
+            return None;
+        }
+        return Some(buffer);
+    }
+
+    //==================================================================================================================================================================
+    //Absolute writing
+
+    /// Absolutely write an u8 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: u8 = 10;
+    /// process.write_u8_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_u8_abs(&self, address: usize, value: u8)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an i8 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: i8 = 10;
+    /// process.write_i8_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_i8_abs(&self, address: usize, value: i8)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an u16 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: u16 = 10;
+    /// process.write_u16_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_u16_abs(&self, address: usize, value: u16)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an i16 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: i16 = 10;
+    /// process.write_i16_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_i16_abs(&self, address: usize, value: i16)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an u32 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: u32 = 10;
+    /// process.write_u32_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_u32_abs(&self, address: usize, value: u32)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an i32 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: i32 = 10;
+    /// process.write_i32_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_i32_abs(&self, address: usize, value: i32)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an u64 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: u64 = 10;
+    /// process.write_u64_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_u64_abs(&self, address: usize, value: u64)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an i64 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: i64 = 10;
+    /// process.write_i64_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_i64_abs(&self, address: usize, value: i64)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an f32 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: f32 = 10.0;
+    /// process.write_f32_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_f32_abs(&self, address: usize, value: f32)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write an f64 to an address
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// let data: f64 = 10.0;
+    /// process.write_f64_abs(0x7ff6a1b2c3d4, data);
+    /// ```
+    fn write_f64_abs(&self, address: usize, value: f64)
+    {
+        let buffer = value.to_le_bytes();
+        self.write_memory_abs(address, &buffer);
+    }
+
+    /// Absolutely write a bool to an address as 1 byte, 1 for true and 0 for false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mem_rs::prelude::*;
+    ///
+    /// let mut process = Process::new("name_of_process.exe");
+    /// process.refresh()?;
+    /// process.write_bool_abs(0x7ff6a1b2c3d4, true);
+    /// ```
+    fn write_bool_abs(&self, address: usize, value: bool)
+    {
+        let buffer = [value as u8];
+        self.write_memory_abs(address, &buffer);
+    }
+}
+
+/// Reads up to max_chars characters of char_size bytes until a null character, returning the bytes before the terminator.
+/// Every read starts at the same address, so pointers resolve the string exactly like any other relative read.
+/// The read grows in chunks, falling back to single characters when a chunk crosses into unreadable memory.
+fn read_null_terminated<F: Fn(&mut [u8]) -> bool>(read: F, max_chars: usize, char_size: usize) -> Vec<u8>
+{
+    //A large max_chars only limits the read, the buffer is allocated as the read grows
+    let max_len = max_chars.saturating_mul(char_size) / char_size * char_size;
+    let mut buffer = Vec::new();
+    let mut valid = 0;
+    let mut length = usize::min(64 * char_size, max_len);
+    let mut char_by_char = false;
+
+    while length > valid
+    {
+        buffer.resize(length, 0);
+        if read(&mut buffer[..length])
+        {
+            if let Some(terminator) = buffer[valid..length].chunks_exact(char_size).position(|c| c.iter().all(|b| *b == 0))
+            {
+                valid += terminator * char_size;
+                break;
+            }
+            valid = length;
+            length = if char_by_char { usize::min(valid + char_size, max_len) } else { usize::min(length.saturating_mul(2), max_len) };
+        }
+        else if !char_by_char
+        {
+            char_by_char = true;
+            length = valid + char_size;
+        }
+        else
+        {
+            break;
+        }
+    }
+    buffer.truncate(valid);
+    return buffer;
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::prelude::*;
+
+    const BASE: usize = 0x140000000;
+
+    #[test]
+    fn typed_reads_are_little_endian()
+    {
+        let process = Process::new_from_buffer(vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08], BASE);
+
+        assert_eq!(process.read_u8_rel(Some(0)), 0x01);
+        assert_eq!(process.read_i16_rel(Some(0)), 0x0201);
+        assert_eq!(process.read_u32_rel(Some(0)), 0x04030201);
+        assert_eq!(process.read_u64_rel(Some(0)), 0x0807060504030201);
+        assert_eq!(process.read_f32_rel(Some(0)), f32::from_le_bytes([0x01, 0x02, 0x03, 0x04]));
+    }
+
+    #[test]
+    fn typed_writes_are_little_endian()
+    {
+        let process = Process::new_from_buffer(vec![0; 8], BASE);
+
+        process.write_u32_rel(Some(0), 0x04030201);
+        let mut buffer = [0u8; 4];
+        assert!(process.read_memory_rel(Some(0), &mut buffer));
+        assert_eq!(buffer, [0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn u16_round_trips()
+    {
+        let process = Process::new_from_buffer(vec![0; 4], BASE);
+
+        process.write_u16_rel(Some(2), 0xBEEF);
+        assert_eq!(process.read_u16_rel(Some(2)), 0xBEEF);
+        assert_eq!(process.read_u16_rel(Some(0)), 0);
+        process.write_i16_rel(Some(0), -2);
+        assert_eq!(process.read_i16_rel(Some(0)), -2);
+    }
+
+    #[test]
+    fn read_bytes_round_trips()
+    {
+        let process = Process::new_from_buffer(vec![0; 8], BASE);
+
+        assert!(process.write_memory_rel(Some(2), &[0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(process.read_bytes_rel(Some(2), 4), Some(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+        assert_eq!(process.read_bytes_abs(BASE + 1, 3), Some(vec![0x00, 0xDE, 0xAD]));
+        //Reads that run past the end of the buffer fail instead of returning a partial read
+        assert_eq!(process.read_bytes_rel(Some(6), 4), None);
+    }
+
+    #[test]
+    fn strings_read_without_a_length_limit()
+    {
+        let mut memory = b"hello\0world".to_vec();
+        memory.extend_from_slice(&[b'a', 0, b'b', 0, 0, 0]);
+        let process = Process::new_from_buffer(memory, BASE);
+
+        assert_eq!(process.read_string_rel(Some(0), usize::MAX), "hello");
+        assert_eq!(process.read_string_rel(Some(0), 3), "hel");
+        //Unterminated up to the end of the buffer, the chunked read falls back to single bytes
+        assert_eq!(process.read_string_rel(Some(6), usize::MAX), "worlda");
+        assert_eq!(process.read_utf16_string_rel(Some(11), usize::MAX), "ab");
+    }
+
+    #[test]
+    fn zero_length_reads_and_writes_succeed()
+    {
+        let process = Process::new_from_buffer(vec![0x01, 0x02, 0x03, 0x04], BASE);
+
+        assert!(process.read_memory_rel(Some(0), &mut []));
+        assert!(process.write_memory_rel(Some(0), &[]));
+        //Also at the end of the buffer and outside of it, nothing is read or written
+        assert!(process.read_memory_rel(Some(4), &mut []));
+        assert!(process.read_memory_abs(0x1234, &mut []));
+        assert!(process.write_memory_abs(0x1234, &[]));
+        assert_eq!(process.read_bytes_rel(Some(0), 0), Some(vec![]));
+
+        let mut buffer = [0u8; 4];
+        assert!(process.read_memory_rel(Some(0), &mut buffer));
+        assert_eq!(buffer, [0x01, 0x02, 0x03, 0x04]);
+    }
+}
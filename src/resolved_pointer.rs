@@ -0,0 +1,113 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::read_write::{BaseReadWrite, ReadWrite};
+use crate::process_data::ProcessData;
+
+/// Represents a pointer path that has been resolved once. Relative reads and writes go directly to the cached
+/// address plus the offset, without walking the pointer path again. The cached address can become invalid
+/// when the pointer path changes, it is up to the user to resolve the pointer again.
+/// Should always be constructed via Pointer::resolve_cached.
+///
+/// # Example
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let mut process = Process::new("name_of_process.exe");
+/// process.refresh()?;
+/// let pointer = process.create_pointer(0x1234, vec![0x10, 0x20]);
+/// let resolved = pointer.resolve_cached();
+/// let health = resolved.read_u32_rel(Some(0x40));
+/// let stamina = resolved.read_u32_rel(Some(0x44));
+/// ```
+pub struct ResolvedPointer
+{
+    process_data: Rc<RefCell<ProcessData>>,
+    address: usize,
+}
+
+impl ResolvedPointer
+{
+    pub(crate) fn new(process_data: Rc<RefCell<ProcessData>>, address: usize) -> Self
+    {
+        ResolvedPointer
+        {
+            process_data,
+            address,
+        }
+    }
+
+    /// Get the cached address of this pointer. Returns 0 if a null pointer was encountered while resolving.
+    pub fn get_address(&self) -> usize
+    {
+        return self.address;
+    }
+}
+
+impl BaseReadWrite for ResolvedPointer
+{
+    fn read_memory_rel(&self, offset: Option<usize>, buffer: &mut [u8]) -> bool
+    {
+        let mut address = self.address;
+        if offset.is_some()
+        {
+            address += offset.unwrap();
+        }
+        return self.read_memory_abs(address, buffer);
+    }
+
+    fn write_memory_rel(&self, offset: Option<usize>, buffer: &[u8]) -> bool
+    {
+        let mut address = self.address;
+        if offset.is_some()
+        {
+            address += offset.unwrap();
+        }
+        return self.write_memory_abs(address, buffer);
+    }
+
+    fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        if self.process_data.borrow().from_buffer
+        {
+            return self.process_data.borrow().read_buffer(address, buffer);
+        }
+        return self.read_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
+    }
+
+    fn write_memory_abs(&self, address: usize, buffer: &[u8]) -> bool
+    {
+        if self.process_data.borrow().dry_run_write(address, buffer)
+        {
+            return true;
+        }
+        if self.process_data.borrow().reject_read_only_write(address)
+        {
+            return false;
+        }
+        if self.process_data.borrow().from_buffer
+        {
+            //Fails instead of panicking while the process data is borrowed, for example through try_get_main_module
+            return self.process_data.try_borrow_mut().is_ok_and(|mut process_data| process_data.write_buffer(address, buffer));
+        }
+        return self.write_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
+    }
+}
+
+impl ReadWrite for ResolvedPointer{}
@@ -0,0 +1,152 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use crate::pointer::Pointer;
+use crate::process::Process;
+use crate::process_data::ProcessData;
+use crate::read_write::{BaseReadWrite, ReadWrite};
+
+/// A pointer whose base address is defined by a relative scan instead of a fixed address.
+/// The scan is re-evaluated automatically when the process has been re-attached (or attached for the first time),
+/// so the pointer keeps working across restarts of the target. Should always be constructed via Process::scan_pointer.
+///
+/// # Example
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let mut process = Process::new("name_of_process.exe");
+/// let game_data_man = process.scan_pointer("48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60", 3, 7, vec![0]);
+/// process.refresh()?;
+/// let igt = game_data_man.read_u32_rel(Some(0xa4));
+/// ```
+pub struct ScanPointer
+{
+    process_data: Rc<RefCell<ProcessData>>,
+    pattern: String,
+    scan_offset: usize,
+    instruction_size: usize,
+    offsets: Vec<usize>,
+
+    pointer: RefCell<Pointer>,
+    scanned_id: Cell<u32>,
+}
+
+impl ScanPointer
+{
+    pub(crate) fn new(process_data: Rc<RefCell<ProcessData>>, pattern: &str, scan_offset: usize, instruction_size: usize, offsets: Vec<usize>) -> Self
+    {
+        ScanPointer
+        {
+            process_data,
+            pattern: String::from(pattern),
+            scan_offset,
+            instruction_size,
+            offsets,
+            pointer: RefCell::new(Pointer::default()),
+            scanned_id: Cell::new(0),
+        }
+    }
+
+    /// Re-run the scan against the attached process and update the base address.
+    /// When the scan fails, reads and writes fail until the next successful scan.
+    pub fn rescan(&self) -> Result<(), String>
+    {
+        //Remember the attempt, so that a failing scan isn't repeated on every read
+        self.scanned_id.set(self.process_data.borrow().id);
+
+        match Process::scan_rel_with_data(&self.process_data, &self.pattern, &self.pattern, self.scan_offset, self.instruction_size, 4, self.offsets.clone())
+        {
+            Ok(pointer) =>
+            {
+                *self.pointer.borrow_mut() = pointer;
+                return Ok(());
+            }
+            Err(e) =>
+            {
+                *self.pointer.borrow_mut() = Pointer::default();
+                return Err(e);
+            }
+        }
+    }
+
+    /// Get the current base address of this pointer, the result of the last successful scan.
+    pub fn get_base_address(&self) -> usize
+    {
+        self.ensure_scanned();
+        return self.pointer.borrow().get_base_address();
+    }
+
+    fn ensure_scanned(&self)
+    {
+        let (attached, id) =
+        {
+            let process_data = self.process_data.borrow();
+            (process_data.attached, process_data.id)
+        };
+
+        if attached && id != self.scanned_id.get()
+        {
+            let _ = self.rescan();
+        }
+    }
+}
+
+impl BaseReadWrite for ScanPointer
+{
+    fn read_memory_rel(&self, offset: Option<usize>, buffer: &mut [u8]) -> bool
+    {
+        self.ensure_scanned();
+        return self.pointer.borrow().read_memory_rel(offset, buffer);
+    }
+
+    fn write_memory_rel(&self, offset: Option<usize>, buffer: &[u8]) -> bool
+    {
+        self.ensure_scanned();
+        return self.pointer.borrow().write_memory_rel(offset, buffer);
+    }
+
+    fn read_memory_abs(&self, address: usize, buffer: &mut [u8]) -> bool
+    {
+        if self.process_data.borrow().from_buffer
+        {
+            return self.process_data.borrow().read_buffer(address, buffer);
+        }
+        return self.read_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
+    }
+
+    fn write_memory_abs(&self, address: usize, buffer: &[u8]) -> bool
+    {
+        if self.process_data.borrow().dry_run_write(address, buffer)
+        {
+            return true;
+        }
+        if self.process_data.borrow().reject_read_only_write(address)
+        {
+            return false;
+        }
+        if self.process_data.borrow().from_buffer
+        {
+            //Fails instead of panicking while the process data is borrowed, for example through try_get_main_module
+            return self.process_data.try_borrow_mut().is_ok_and(|mut process_data| process_data.write_buffer(address, buffer));
+        }
+        return self.write_with_handle(self.process_data.borrow().handle.raw(), address, buffer);
+    }
+}
+
+impl ReadWrite for ScanPointer{}
@@ -0,0 +1,130 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::HashMap;
+
+/// How the address of a signature is resolved after the pattern is found
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SignatureKind
+{
+    /// The pattern contains an absolute address, see Process::scan_abs
+    Abs,
+    /// The pattern contains an instruction relative address, see Process::scan_rel
+    Rel,
+}
+
+/// A single named signature, the arguments to Process::scan_abs or Process::scan_rel
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Signature
+{
+    pub kind: SignatureKind,
+    pub pattern: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub scan_offset: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub instruction_size: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub offsets: Vec<usize>,
+}
+
+/// A set of named signatures that can be resolved at once with Process::scan_set.
+/// With the serde feature enabled, a SignatureSet can be deserialized from any format, such as toml or json,
+/// as a map of signature names to signatures. scan_offset, instruction_size and offsets are optional.
+/// With the toml feature enabled, load_from_str reads it from toml.
+///
+/// # Examples
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let set = SignatureSet::load_from_str(r#"
+///     [GameDataMan]
+///     kind = "rel"
+///     pattern = "48 8b 05 ? ? ? ? 48 8b 50 10 48 89 54 24 60"
+///     scan_offset = 3
+///     instruction_size = 7
+///     offsets = [0]
+/// "#)?;
+///
+/// let mut process = Process::new("name_of_process.exe");
+/// process.refresh()?;
+/// let pointers = process.scan_set(&set);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SignatureSet
+{
+    pub signatures: HashMap<String, Signature>,
+}
+
+impl SignatureSet
+{
+    /// Parses a signature set from toml, with a table per signature name
+    #[cfg(feature = "toml")]
+    pub fn load_from_str(str: &str) -> Result<SignatureSet, String>
+    {
+        return toml::from_str(str).map_err(|e| e.to_string());
+    }
+
+    pub fn insert(&mut self, name: &str, signature: Signature)
+    {
+        self.signatures.insert(String::from(name), signature);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Signature>
+    {
+        return self.signatures.get(name);
+    }
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn loads_signatures_from_toml()
+    {
+        let set = SignatureSet::load_from_str(r#"
+            [GameDataMan]
+            kind = "rel"
+            pattern = "48 8b 05 ? ? ? ?"
+            scan_offset = 3
+            instruction_size = 7
+            offsets = [0, 0x10]
+
+            [Health]
+            kind = "abs"
+            pattern = "56 8B F1"
+        "#).unwrap();
+
+        let game_data_man = set.get("GameDataMan").unwrap();
+        assert_eq!(game_data_man.kind, SignatureKind::Rel);
+        assert_eq!((game_data_man.scan_offset, game_data_man.instruction_size), (3, 7));
+        assert_eq!(game_data_man.offsets, vec![0, 0x10]);
+
+        let health = set.get("Health").unwrap();
+        assert_eq!(health.kind, SignatureKind::Abs);
+        assert_eq!((health.scan_offset, health.instruction_size), (0, 0));
+        assert!(health.offsets.is_empty());
+
+        assert!(SignatureSet::load_from_str("[Health]\nkind = \"other\"\npattern = \"56\"").is_err());
+    }
+}
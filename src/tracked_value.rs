@@ -0,0 +1,74 @@
+// This file is part of the mem-rs distribution (https://github.com/FrankvdStam/mem-rs).
+// Copyright (c) 2022 Frank van der Stam.
+// https://github.com/FrankvdStam/mem-rs/blob/main/LICENSE
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, version 3.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::pointer::Pointer;
+use crate::read_write::{Pod, ReadWrite};
+
+/// Wraps a pointer and offset and remembers the last value that was read, for detecting changes in a poll loop.
+/// T is read from raw bytes, so it must be Pod. For flags, track a u8 and compare it against 0.
+///
+/// # Examples
+///
+/// ```
+/// use mem_rs::prelude::*;
+///
+/// let mut process = Process::new("name_of_process.exe");
+/// process.refresh()?;
+/// let pointer = process.create_pointer(0x1234, vec![0]);
+/// let mut health: TrackedValue<u32> = TrackedValue::new(pointer, Some(0x3e8));
+///
+/// let (value, changed) = health.poll();
+/// if changed
+/// {
+///     println!("health: {}", value);
+/// }
+/// ```
+pub struct TrackedValue<T: Pod + PartialEq + Default>
+{
+    pointer: Pointer,
+    offset: Option<usize>,
+    last: Option<T>,
+}
+
+impl<T: Pod + PartialEq + Default> TrackedValue<T>
+{
+    pub fn new(pointer: Pointer, offset: Option<usize>) -> Self
+    {
+        TrackedValue { pointer, offset, last: None }
+    }
+
+    /// Reads the current value and returns it together with whether it differs from the previously polled value.
+    /// The first successful poll always reports a change. When the read fails, the previous value is returned unchanged.
+    pub fn poll(&mut self) -> (T, bool)
+    {
+        match self.pointer.try_read_struct_rel::<T>(self.offset)
+        {
+            Some(value) =>
+            {
+                let changed = self.last != Some(value);
+                self.last = Some(value);
+                return (value, changed);
+            }
+            None => return (self.last.unwrap_or_default(), false),
+        }
+    }
+
+    /// Returns the last polled value, without reading memory
+    pub fn last(&self) -> Option<T>
+    {
+        return self.last;
+    }
+}